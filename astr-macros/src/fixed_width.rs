@@ -0,0 +1,255 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Data, DeriveInput, Error, Expr, LitChar, LitStr, Member, Result, Type};
+
+#[derive(Default)]
+struct StructAttrs {
+    filler: Option<char>,
+    trailing: Option<Expr>,
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    width: Option<Expr>,
+    pad: Option<char>,
+    align: Option<TokenStream>,
+    skip: Option<Expr>,
+}
+
+struct Field {
+    member: Member,
+    name: String,
+    ty: Type,
+    attrs: FieldAttrs,
+}
+
+impl Field {
+    fn width(&self) -> TokenStream {
+        let ty = &self.ty;
+        match &self.attrs.width {
+            Some(width) => quote!((#width)),
+            None => quote!(<#ty as ::astr::fixed_width::FixedField>::WIDTH),
+        }
+    }
+
+    fn skip(&self) -> TokenStream {
+        match &self.attrs.skip {
+            Some(skip) => quote!((#skip)),
+            None => quote!(0),
+        }
+    }
+}
+
+fn parse_struct_attrs(input: &DeriveInput) -> Result<StructAttrs> {
+    let mut attrs = StructAttrs::default();
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("fixed_width"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("filler") {
+                let lit: LitChar = meta.value()?.parse()?;
+                if !lit.value().is_ascii() {
+                    return Err(Error::new(lit.span(), "filler must be an ASCII char"));
+                }
+                attrs.filler = Some(lit.value());
+            } else if meta.path.is_ident("trailing") {
+                attrs.trailing = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("unknown fixed_width attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(attrs)
+}
+
+fn parse_field_attrs(field: &syn::Field) -> Result<FieldAttrs> {
+    let mut attrs = FieldAttrs::default();
+    let mut align_right = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("fixed_width"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("width") {
+                attrs.width = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("pad") {
+                let lit: LitChar = meta.value()?.parse()?;
+                if !lit.value().is_ascii() {
+                    return Err(Error::new(lit.span(), "pad must be an ASCII char"));
+                }
+                attrs.pad = Some(lit.value());
+            } else if meta.path.is_ident("align") {
+                let lit: LitStr = meta.value()?.parse()?;
+                align_right = lit.value() == "right";
+                attrs.align = Some(match lit.value().as_str() {
+                    "left" => quote!(::astr::Align::Left),
+                    "right" => quote!(::astr::Align::Right),
+                    "center" => quote!(::astr::Align::Center),
+                    _ => {
+                        return Err(Error::new(
+                            lit.span(),
+                            "expected `\"left\"`, `\"right\"` or `\"center\"`",
+                        ))
+                    }
+                });
+            } else if meta.path.is_ident("skip") {
+                attrs.skip = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("unknown fixed_width attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    if attrs.width.is_none() && (attrs.pad.is_some() || attrs.align.is_some()) {
+        return Err(Error::new_spanned(
+            field,
+            "`pad` and `align` need a `width`, fields without one are not padded",
+        ));
+    }
+    if attrs.pad.is_some_and(|pad| pad.is_ascii_digit()) && !align_right {
+        return Err(Error::new_spanned(
+            field,
+            "a digit `pad` needs `align = \"right\"`, trailing digits can not be told apart from the value",
+        ));
+    }
+    Ok(attrs)
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "FixedWidth can not be derived for generic structs",
+        ));
+    }
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "FixedWidth can only be derived for structs",
+            ))
+        }
+    };
+
+    let struct_attrs = parse_struct_attrs(&input)?;
+    let fields = data
+        .fields
+        .iter()
+        .zip(data.fields.members())
+        .map(|(field, member)| {
+            Ok(Field {
+                name: match &member {
+                    Member::Named(ident) => ident.to_string(),
+                    Member::Unnamed(index) => index.index.to_string(),
+                },
+                member,
+                ty: field.ty.clone(),
+                attrs: parse_field_attrs(field)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let ident = &input.ident;
+    let record_ty = quote!(::astr::AStr<{ #ident::WIDTH }>);
+    let error_ty = quote!(::astr::fixed_width::FixedWidthError);
+    let filler = struct_attrs.filler.unwrap_or(' ') as u8;
+
+    // offsets are sums of all widths and skips in front of a field
+    let mut offset = quote!(0);
+    let mut parse_fields = Vec::new();
+    let mut write_fields = Vec::new();
+    for field in &fields {
+        let skip = field.skip();
+        offset = quote!(#offset + #skip);
+
+        let Field {
+            member, name, ty, ..
+        } = field;
+        let width = field.width();
+        match &field.attrs.width {
+            None => {
+                parse_fields.push(quote! {
+                    #member: ::astr::fixed_width::parse_fixed::<#ty>(record, #offset, #name)?
+                });
+                write_fields.push(quote! {
+                    ::astr::fixed_width::write_fixed(&mut record, #offset, &self.#member);
+                });
+            }
+            Some(_) => {
+                let pad = field.attrs.pad.unwrap_or(' ');
+                let align = field
+                    .attrs
+                    .align
+                    .clone()
+                    .unwrap_or_else(|| quote!(::astr::Align::Left));
+                parse_fields.push(quote! {
+                    #member: ::astr::fixed_width::parse_padded::<#ty>(
+                        record, #offset, #width, #pad, #align, #name
+                    )?
+                });
+                write_fields.push(quote! {
+                    ::astr::fixed_width::write_padded::<_, { #width }>(
+                        &mut record, #offset, &self.#member, #pad, #align, #name
+                    )?;
+                });
+            }
+        }
+
+        offset = quote!(#offset + #width);
+    }
+    let trailing = struct_attrs
+        .trailing
+        .map_or_else(|| quote!(0), |t| quote!((#t)));
+    let total = quote!(#offset + #trailing);
+
+    let unused_record = fields.is_empty().then(|| quote!(let _ = record;));
+
+    Ok(quote! {
+        impl #ident {
+            /// Byte length of a whole record.
+            pub const WIDTH: usize = #total;
+
+            /// Parse a record.
+            pub fn parse(record: &#record_ty) -> ::core::result::Result<Self, #error_ty> {
+                let record = record.as_str();
+                #unused_record
+                ::core::result::Result::Ok(Self {
+                    #(#parse_fields,)*
+                })
+            }
+
+            /// Parse a record from a str of exactly `WIDTH` bytes.
+            pub fn parse_str(record: &str) -> ::core::result::Result<Self, #error_ty> {
+                let record = <#record_ty>::try_from_str_ref(record).map_err(#error_ty::Record)?;
+                Self::parse(record)
+            }
+
+            /// Write all fields into a record.
+            pub fn try_to_record(&self) -> ::core::result::Result<#record_ty, #error_ty> {
+                #[allow(unused_mut)]
+                let mut record = [#filler; #ident::WIDTH];
+                #(#write_fields)*
+                <#record_ty>::try_from_utf8_array_ref(&record)
+                    .copied()
+                    .map_err(#error_ty::Record)
+            }
+
+            /// Write all fields into a record.
+            ///
+            /// # Panics
+            /// Panics if a field does not fit into its width.
+            pub fn to_record(&self) -> #record_ty {
+                match self.try_to_record() {
+                    ::core::result::Result::Ok(record) => record,
+                    ::core::result::Result::Err(err) => ::core::panic!("{}", err),
+                }
+            }
+        }
+    }
+    .into_token_stream())
+}
@@ -0,0 +1,340 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, Expr, GenericArgument, Lit, LitStr, PathArguments, Result, Token, Type,
+};
+
+/// `"format string", expr: Type, ...`
+pub struct Input {
+    format: LitStr,
+    args: Vec<Arg>,
+}
+
+struct Arg {
+    expr: Expr,
+    ty: Type,
+    kind: Kind,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let format = input.parse()?;
+        let mut args = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            let parsed =
+                Punctuated::<(Expr, Type), Token![,]>::parse_terminated_with(input, |input| {
+                    let expr = input.parse()?;
+                    input.parse::<Token![:]>()?;
+                    Ok((expr, input.parse()?))
+                })?;
+            for (expr, ty) in parsed {
+                let kind = Kind::of(&ty)?;
+                args.push(Arg { expr, ty, kind });
+            }
+        }
+        Ok(Self { format, args })
+    }
+}
+
+/// The argument types with a known range of output lengths.
+#[derive(Clone, Copy)]
+enum Kind {
+    Int { signed: bool, bits: u32 },
+    Bool,
+    AStr(usize),
+}
+
+impl Kind {
+    fn of(ty: &Type) -> Result<Self> {
+        let unsupported = || {
+            Error::new_spanned(
+                ty,
+                "unsupported type, expected a fixed size integer, `bool` or `AStr<N>` with a literal `N`",
+            )
+        };
+        let path = match ty {
+            Type::Reference(r) => return Self::of(&r.elem),
+            Type::Paren(p) => return Self::of(&p.elem),
+            Type::Group(g) => return Self::of(&g.elem),
+            Type::Path(p) if p.qself.is_none() => &p.path,
+            _ => return Err(unsupported()),
+        };
+        let segment = path.segments.last().ok_or_else(unsupported)?;
+        let name = segment.ident.to_string();
+        if name == "AStr" {
+            let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+                return Err(unsupported());
+            };
+            let mut args = generics.args.iter();
+            let (Some(GenericArgument::Const(Expr::Lit(lit))), None) = (args.next(), args.next())
+            else {
+                return Err(unsupported());
+            };
+            let Lit::Int(len) = &lit.lit else {
+                return Err(unsupported());
+            };
+            return Ok(Self::AStr(len.base10_parse()?));
+        }
+        if !segment.arguments.is_none() {
+            return Err(unsupported());
+        }
+        let (signed, bits) = match name.as_str() {
+            "bool" => return Ok(Self::Bool),
+            "u8" => (false, 8),
+            "u16" => (false, 16),
+            "u32" => (false, 32),
+            "u64" => (false, 64),
+            "u128" => (false, 128),
+            "i8" => (true, 8),
+            "i16" => (true, 16),
+            "i32" => (true, 32),
+            "i64" => (true, 64),
+            "i128" => (true, 128),
+            _ => return Err(unsupported()),
+        };
+        Ok(Self::Int { signed, bits })
+    }
+}
+
+/// A parsed `{...}` placeholder.
+struct Spec<'a> {
+    text: &'a str,
+    fill: char,
+    plus: bool,
+    alternate: bool,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    ty: &'a str,
+}
+
+impl<'a> Spec<'a> {
+    fn parse(text: &'a str, spec: &'a str) -> core::result::Result<Self, String> {
+        let mut rest = spec;
+        let mut fill = ' ';
+        let mut chars = rest.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), Some('<' | '^' | '>')) => {
+                fill = c;
+                rest = &rest[c.len_utf8() + 1..];
+            }
+            (Some('<' | '^' | '>'), _) => rest = &rest[1..],
+            _ => {}
+        }
+        let mut flag = |c: char| match rest.strip_prefix(c) {
+            Some(r) => {
+                rest = r;
+                true
+            }
+            None => false,
+        };
+        let plus = flag('+');
+        flag('-');
+        let alternate = flag('#');
+        let zero = flag('0') && !rest.starts_with('$');
+
+        let number = |rest: &mut &'a str| -> core::result::Result<Option<usize>, String> {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (digits, tail) = rest.split_at(end);
+            if tail.starts_with('$') || tail.starts_with('*') {
+                return Err(from_argument(text));
+            }
+            *rest = tail;
+            Ok(if digits.is_empty() {
+                None
+            } else {
+                Some(
+                    digits
+                        .parse()
+                        .map_err(|_| format!("invalid width in `{}`", text))?,
+                )
+            })
+        };
+        if rest.starts_with(|c: char| c.is_alphabetic() || c == '_') && rest.contains('$') {
+            return Err(from_argument(text));
+        }
+        let width = number(&mut rest)?.unwrap_or(0);
+        let precision = match rest.strip_prefix('.') {
+            Some(r) => {
+                rest = r;
+                number(&mut rest)?
+            }
+            None => None,
+        };
+        Ok(Self {
+            text,
+            fill,
+            plus,
+            alternate,
+            zero,
+            width,
+            precision,
+            ty: rest,
+        })
+    }
+
+    /// The exact byte length of `kind` formatted with this spec.
+    fn len(&self, kind: Kind, ty: &Type) -> core::result::Result<usize, String> {
+        let unsupported = || {
+            format!(
+                "`{}` is not supported for `{}`",
+                self.text,
+                quote!(#ty).to_string().replace(' ', "")
+            )
+        };
+        // (min chars, max chars, fixed byte length or `None` if bytes equal chars)
+        let (min, max, bytes, fill) = match kind {
+            Kind::Int { signed, bits } => {
+                let radix = match self.ty {
+                    "" | "?" => 10,
+                    "x" | "X" | "x?" | "X?" => 16,
+                    "o" => 8,
+                    "b" => 2,
+                    _ => return Err(unsupported()),
+                };
+                let magnitude = match (radix, signed) {
+                    (10, true) => 1u128 << (bits - 1),
+                    _ => u128::MAX >> (128 - bits),
+                };
+                let sign = match (self.plus, radix == 10 && signed) {
+                    (true, _) => (1, 1),
+                    (false, true) => (0, 1),
+                    (false, false) => (0, 0),
+                };
+                let prefix = if self.alternate && radix != 10 { 2 } else { 0 };
+                let fill = if self.zero { '0' } else { self.fill };
+                let max = digits(magnitude, radix) + sign.1 + prefix;
+                (1 + sign.0 + prefix, max, None, fill)
+            }
+            Kind::Bool => {
+                if !matches!(self.ty, "" | "?") {
+                    return Err(unsupported());
+                }
+                let precision = self.precision.unwrap_or(usize::MAX);
+                (precision.min(4), precision.min(5), None, self.fill)
+            }
+            Kind::AStr(len) => {
+                if !self.ty.is_empty() {
+                    return Err(unsupported());
+                }
+                if self.precision.is_some_and(|p| p < len) {
+                    return Err(format!(
+                        "the precision of `{}` may cut off part of an `AStr<{}>`, so its length is not fixed",
+                        self.text, len
+                    ));
+                }
+                (len.div_ceil(4), len, Some(len), self.fill)
+            }
+        };
+
+        let total = |chars: usize| {
+            bytes.unwrap_or(chars) + self.width.saturating_sub(chars) * fill.len_utf8()
+        };
+        let len = total(min);
+        if total(max) != len || total(self.width.clamp(min, max)) != len {
+            let hint = match kind {
+                Kind::AStr(_) => format!("use no width or a width of at most {}", min),
+                _ if fill.len_utf8() == 1 => format!("use a width of at least {}", max),
+                _ => "use an ASCII fill char and a wider width".to_string(),
+            };
+            return Err(format!(
+                "the length of `{}` is not fixed for `{}`, {}",
+                self.text,
+                quote!(#ty).to_string().replace(' ', ""),
+                hint
+            ));
+        }
+        Ok(len)
+    }
+}
+
+fn from_argument(text: &str) -> String {
+    format!(
+        "`{}` takes its width or precision from an argument, so its length is not fixed",
+        text
+    )
+}
+
+fn digits(mut n: u128, radix: u128) -> usize {
+    let mut digits = 1;
+    while n >= radix {
+        n /= radix;
+        digits += 1;
+    }
+    digits
+}
+
+/// Byte length of the formatted output, checking that it is the same for every argument value.
+fn output_len(input: &Input) -> Result<usize> {
+    let error = |msg: String| Error::new(input.format.span(), msg);
+    let format = input.format.value();
+    let mut len = 0;
+    let mut next_arg = 0;
+    let mut rest = format.as_str();
+    while let Some(i) = rest.find(['{', '}']) {
+        len += i;
+        let tail = &rest[i..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            len += 1;
+            rest = &tail[2..];
+            continue;
+        }
+        let end = tail
+            .find('}')
+            .filter(|_| tail.starts_with('{'))
+            .ok_or_else(|| error("invalid format string".to_string()))?;
+        let text = &tail[..=end];
+        let (arg, spec) = tail[1..end].split_once(':').unwrap_or((&tail[1..end], ""));
+        let index = match arg.trim() {
+            "" => {
+                next_arg += 1;
+                next_arg - 1
+            }
+            arg => arg.parse::<usize>().map_err(|_| {
+                error(format!(
+                    "`{}` uses a named argument, only positional `expr: Type` arguments are supported",
+                    text
+                ))
+            })?,
+        };
+        let arg = input
+            .args
+            .get(index)
+            .ok_or_else(|| error(format!("`{}` has no argument", text)))?;
+        len += Spec::parse(text, spec)
+            .and_then(|spec| spec.len(arg.kind, &arg.ty))
+            .map_err(error)?;
+        rest = &tail[end + 1..];
+    }
+    Ok(len + rest.len())
+}
+
+pub fn expand(input: Input) -> Result<TokenStream> {
+    let len = output_len(&input)?;
+    let format = &input.format;
+    let names: Vec<_> = (0..input.args.len())
+        .map(|i| format_ident!("arg{}", i, span = Span::mixed_site()))
+        .collect();
+    let exprs = input.args.iter().map(|arg| &arg.expr);
+    let types = input.args.iter().map(|arg| &arg.ty);
+
+    Ok(quote! {
+        match (#(&(#exprs),)*) {
+            (#(#names,)*) => {
+                #(let #names: &#types = #names;)*
+                let result: ::core::result::Result<::astr::AStr<#len>, ::astr::AStrError> =
+                    ::astr::AStr::try_from_args(::core::format_args!(#format, #(#names),*));
+                match result {
+                    ::core::result::Result::Ok(s) => s,
+                    ::core::result::Result::Err(err) => {
+                        ::core::panic!("format_astr_exact! computed a wrong length: {}", err)
+                    }
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,194 @@
+//! Derive and proc macros for the [astr](https://docs.rs/astr) crate.
+//!
+//! Don't depend on this crate directly, enable the `derive` or `macros` feature of `astr`
+//! instead.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod fixed_width;
+mod format_exact;
+mod newtype;
+
+/// Derive parsing and writing of fixed-width text records.
+///
+/// Each field covers a fixed number of bytes of the record, in declaration order. Fields of a
+/// [`FixedField`] type like `AStr<N>` take their width from the type. Any other field needs a
+/// `width` and is parsed with `FromStr` and written with `Display`.
+///
+/// The derive generates these items on the struct:
+/// - `const WIDTH: usize`, the byte length of a whole record
+/// - `fn parse(record: &AStr<WIDTH>) -> Result<Self, FixedWidthError>`
+/// - `fn parse_str(record: &str) -> Result<Self, FixedWidthError>`
+/// - `fn try_to_record(&self) -> Result<AStr<WIDTH>, FixedWidthError>`
+/// - `fn to_record(&self) -> AStr<WIDTH>`, which panics if a field does not fit
+///
+/// ## Attributes
+///
+/// On fields:
+/// - `#[fixed_width(width = N)]` width in bytes of a field that is not a [`FixedField`]
+/// - `#[fixed_width(pad = '0')]` ASCII char used to pad the field, defaults to `' '`, a digit
+///   needs `align = "right"`
+/// - `#[fixed_width(align = "right")]` one of `"left"` (default), `"right"` or `"center"`
+/// - `#[fixed_width(skip = N)]` number of filler bytes in front of the field
+///
+/// On the struct:
+/// - `#[fixed_width(filler = '.')]` ASCII char written to filler bytes, defaults to `' '`
+/// - `#[fixed_width(trailing = N)]` number of filler bytes at the end of the record
+///
+/// Padding is removed before a field is parsed. A field made up of only zero padding parses as
+/// `"0"` if its type rejects an empty str, like the integers do. Filler bytes are ignored when
+/// parsing.
+///
+/// ## Example
+///
+/// ```rust
+/// use astr::{astr, AStr, FixedWidth};
+///
+/// #[derive(FixedWidth)]
+/// #[fixed_width(trailing = 1)]
+/// struct Booking {
+///     account: AStr<6>,
+///     #[fixed_width(skip = 2, width = 8, pad = '0', align = "right")]
+///     cents: u64,
+///     #[fixed_width(width = 10)]
+///     reference: String,
+/// }
+///
+/// let booking = Booking::parse_str("DE0042  00012345rent       ").unwrap();
+/// assert_eq!(booking.account, "DE0042");
+/// assert_eq!(booking.cents, 12345);
+/// assert_eq!(booking.reference, "rent");
+///
+/// assert_eq!(Booking::WIDTH, 27);
+/// assert_eq!(booking.to_record(), "DE0042  00012345rent       ");
+/// ```
+///
+/// Like `filler`, `pad` must be an ASCII char.
+///
+/// ```rust,compile_fail
+/// use astr::FixedWidth;
+///
+/// #[derive(FixedWidth)]
+/// struct Name {
+///     #[fixed_width(width = 3, pad = 'ä')]
+///     name: String,
+/// }
+/// ```
+///
+/// Trailing zero padding would be read back as part of the number, so a digit `pad` must be
+/// right aligned.
+///
+/// ```rust,compile_fail
+/// use astr::FixedWidth;
+///
+/// #[derive(FixedWidth)]
+/// struct Amount {
+///     #[fixed_width(width = 6, pad = '0')]
+///     cents: u32,
+/// }
+/// ```
+///
+/// [`FixedField`]: https://docs.rs/astr/latest/astr/fixed_width/trait.FixedField.html
+#[proc_macro_derive(FixedWidth, attributes(fixed_width))]
+pub fn derive_fixed_width(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    fixed_width::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive the impls of an `AStr` for a newtype around one.
+///
+/// The struct must be a tuple struct with a single field of a [`FixedStr`] type, like
+/// `AStr<N>`. The derive generates:
+/// - `fn try_new(value) -> Result<Self, AStrError>` and `fn into_inner(self)`
+/// - `Deref` to the field type, `AsRef<str>`, `AsRef<[u8]>` and `Borrow<str>`
+/// - `Display`, and `Debug` as `Name("value")`
+/// - `FromStr`, `TryFrom<&str>`, `TryFrom` the field type and `From<Self>` for the field type
+/// - `PartialEq<str>` and `PartialEq<&str>`
+/// - `DynFixedStr` and `FixedStr`
+/// - `Serialize` and `Deserialize` if the `serde` feature of `astr` is enabled
+///
+/// Derive `Clone`, `Copy`, `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` as needed, they
+/// behave like the ones of the field.
+///
+/// ## Validation
+///
+/// `#[astr(validate = path)]` names a `fn(&str) -> Result<(), &'static str>` that is called on
+/// every construction, including deserialization. A rejected value is reported as
+/// `AStrError::Invalid` with the returned reason.
+///
+/// ```rust
+/// use astr::{AStr, AStrError, AStrNewtype};
+///
+/// fn upper(s: &str) -> Result<(), &'static str> {
+///     match s.bytes().all(|b| b.is_ascii_uppercase()) {
+///         true => Ok(()),
+///         false => Err("expected upper case letters"),
+///     }
+/// }
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, AStrNewtype)]
+/// #[astr(validate = upper)]
+/// struct Iata(AStr<3>);
+///
+/// let ber: Iata = "BER".parse().unwrap();
+/// assert_eq!(ber, "BER");
+/// assert_eq!(format!("{:?}", ber), r#"Iata("BER")"#);
+/// assert_eq!(
+///     Iata::try_from("ber"),
+///     Err(AStrError::Invalid { reason: "expected upper case letters" })
+/// );
+/// ```
+///
+/// [`FixedStr`]: https://docs.rs/astr/latest/astr/trait.FixedStr.html
+#[proc_macro_derive(AStrNewtype, attributes(astr))]
+pub fn derive_astr_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    newtype::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Format into an [`AStr`] whose length is checked at compile time.
+///
+/// Works like `format_astr!`, but every argument is written as `expr: Type`. From the literal
+/// parts of the format string, the flags of each placeholder and the argument types, the macro
+/// computes the exact byte length of the output. If the length could differ between argument
+/// values, the macro fails to compile. The result is a plain `AStr<N>` instead of a `Result`.
+///
+/// Supported argument types are the fixed size integers `u8` to `u128` and `i8` to `i128`,
+/// `bool` and `AStr<N>` with a literal `N`, or references to them. Integers can use the
+/// `Display`, `Debug`, `x`, `X`, `o` and `b` formats. Only positional arguments are supported,
+/// widths and precisions must be literals.
+///
+/// ```rust
+/// use astr::{astr, format_astr_exact, AStr};
+///
+/// let color = 0xFA80u16;
+/// let code = astr!("ab");
+/// let id: AStr<15> = format_astr_exact!("#{:06X}-{:04}:{}", color: u16, 42: u8, code: &AStr<2>);
+/// assert_eq!(id, "#00FA80-0042:ab");
+///
+/// // the length is inferred
+/// let s = format_astr_exact!("{:>5}|{:+04}", true: bool, 7: i8);
+/// assert_eq!(s, " true|+007");
+/// ```
+///
+/// An `u32` can have up to ten digits, so a width of four does not fix its length.
+///
+/// ```rust,compile_fail
+/// use astr::format_astr_exact;
+///
+/// let s = format_astr_exact!("{:04}", 42: u32);
+/// ```
+///
+/// [`AStr`]: https://docs.rs/astr/latest/astr/struct.AStr.html
+#[proc_macro]
+pub fn format_astr_exact(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as format_exact::Input);
+    format_exact::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
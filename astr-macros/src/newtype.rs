@@ -0,0 +1,173 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Path, Result};
+
+fn parse_validate(input: &DeriveInput) -> Result<Option<Path>> {
+    let mut validate = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("astr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("validate") {
+                validate = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("unknown astr attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(validate)
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "AStrNewtype can not be derived for generic structs",
+        ));
+    }
+    let ty = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "AStrNewtype needs a tuple struct with a single field",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "AStrNewtype can only be derived for structs",
+            ))
+        }
+    };
+
+    let validate = parse_validate(&input)?.map(|path| {
+        quote! {
+            if let ::core::result::Result::Err(reason) =
+                #path(::astr::DynFixedStr::as_str(&value))
+            {
+                return ::core::result::Result::Err(::astr::AStrError::Invalid { reason });
+            }
+        }
+    });
+    let ident = &input.ident;
+    let name = ident.to_string();
+
+    Ok(quote! {
+        impl #ident {
+            /// Wrap a value, running the validation hook if there is one.
+            pub fn try_new(value: #ty) -> ::core::result::Result<Self, ::astr::AStrError> {
+                #validate
+                ::core::result::Result::Ok(Self(value))
+            }
+
+            /// Get the wrapped value.
+            pub fn into_inner(self) -> #ty {
+                self.0
+            }
+        }
+
+        impl ::core::ops::Deref for #ident {
+            type Target = #ty;
+
+            fn deref(&self) -> &#ty {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for #ident {
+            fn as_ref(&self) -> &str {
+                ::astr::DynFixedStr::as_str(&self.0)
+            }
+        }
+
+        impl ::core::convert::AsRef<[u8]> for #ident {
+            fn as_ref(&self) -> &[u8] {
+                ::astr::DynFixedStr::as_bytes(&self.0)
+            }
+        }
+
+        impl ::core::borrow::Borrow<str> for #ident {
+            fn borrow(&self) -> &str {
+                ::astr::DynFixedStr::as_str(&self.0)
+            }
+        }
+
+        impl ::core::fmt::Display for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(::astr::DynFixedStr::as_str(&self.0), f)
+            }
+        }
+
+        impl ::core::fmt::Debug for #ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(#name)
+                    .field(&::astr::DynFixedStr::as_str(&self.0))
+                    .finish()
+            }
+        }
+
+        impl ::core::str::FromStr for #ident {
+            type Err = ::astr::AStrError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                Self::try_new(<#ty as ::astr::FixedStr>::try_from_str(s)?)
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for #ident {
+            type Error = ::astr::AStrError;
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_new(<#ty as ::astr::FixedStr>::try_from_str(s)?)
+            }
+        }
+
+        impl ::core::convert::TryFrom<#ty> for #ident {
+            type Error = ::astr::AStrError;
+
+            fn try_from(value: #ty) -> ::core::result::Result<Self, Self::Error> {
+                Self::try_new(value)
+            }
+        }
+
+        impl ::core::convert::From<#ident> for #ty {
+            fn from(value: #ident) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::cmp::PartialEq<str> for #ident {
+            fn eq(&self, other: &str) -> bool {
+                ::astr::DynFixedStr::as_str(&self.0) == other
+            }
+        }
+
+        impl ::core::cmp::PartialEq<&str> for #ident {
+            fn eq(&self, other: &&str) -> bool {
+                ::astr::DynFixedStr::as_str(&self.0) == *other
+            }
+        }
+
+        impl ::astr::DynFixedStr for #ident {
+            fn as_str(&self) -> &str {
+                ::astr::DynFixedStr::as_str(&self.0)
+            }
+        }
+
+        impl ::astr::FixedStr for #ident {
+            const LEN: usize = <#ty as ::astr::FixedStr>::LEN;
+
+            fn try_from_str(s: &str) -> ::core::result::Result<Self, ::astr::AStrError> {
+                Self::try_new(<#ty as ::astr::FixedStr>::try_from_str(s)?)
+            }
+
+            fn try_from_utf8(bytes: &[u8]) -> ::core::result::Result<Self, ::astr::AStrError> {
+                Self::try_new(<#ty as ::astr::FixedStr>::try_from_utf8(bytes)?)
+            }
+        }
+
+        ::astr::__newtype_serde!(#ident, #ty);
+    })
+}
@@ -0,0 +1,126 @@
+use astr::{astr, fixed_width::FixedWidthError, AStr, ArrayString, FixedWidth};
+
+#[derive(Debug, PartialEq, FixedWidth)]
+#[fixed_width(filler = '.', trailing = 2)]
+struct Transfer {
+    iban: AStr<8>,
+    #[fixed_width(skip = 1, width = 6, pad = '0', align = "right")]
+    amount: u32,
+    #[fixed_width(width = 7, align = "center")]
+    name: ArrayString<7>,
+    currency: AStr<3>,
+}
+
+#[derive(Debug, PartialEq, FixedWidth)]
+struct Pair(AStr<2>, #[fixed_width(width = 3, pad = '_')] i16);
+
+#[derive(FixedWidth)]
+struct Empty {}
+
+#[derive(Debug, PartialEq, FixedWidth)]
+struct Zeros {
+    #[fixed_width(width = 6, pad = '0', align = "right")]
+    n: u32,
+    #[fixed_width(width = 3, pad = '0', align = "right")]
+    s: String,
+}
+
+#[test]
+fn test_width() {
+    assert_eq!(Transfer::WIDTH, 8 + 1 + 6 + 7 + 3 + 2);
+    assert_eq!(Pair::WIDTH, 5);
+    assert_eq!(Empty::WIDTH, 0);
+}
+
+#[test]
+fn test_parse() {
+    let record = astr!("DE123456 000420 Jürg EUR  ");
+    let transfer = Transfer::parse(record).unwrap();
+    assert_eq!(
+        transfer,
+        Transfer {
+            iban: *astr!("DE123456"),
+            amount: 420,
+            name: "Jürg".parse().unwrap(),
+            currency: *astr!("EUR"),
+        }
+    );
+
+    assert_eq!(Pair::parse_str("ab-1_").unwrap(), Pair(*astr!("ab"), -1));
+}
+
+#[test]
+fn test_parse_zero() {
+    let transfer = Transfer::parse_str("DE123456 000000 Jürg EUR  ").unwrap();
+    assert_eq!(transfer.amount, 0);
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(matches!(
+        Transfer::parse_str("too short"),
+        Err(FixedWidthError::Record(_))
+    ));
+    assert!(matches!(
+        Transfer::parse_str("DE123456 00x420 Jürg EUR  "),
+        Err(FixedWidthError::Parse {
+            field: "amount",
+            offset: 9
+        })
+    ));
+    assert!(matches!(
+        Pair::parse_str("aä_1"),
+        Err(FixedWidthError::Field { field: "0", .. })
+    ));
+}
+
+#[test]
+fn test_to_record() {
+    let transfer = Transfer {
+        iban: *astr!("DE123456"),
+        amount: 42,
+        name: "Ann".parse().unwrap(),
+        currency: *astr!("EUR"),
+    };
+    assert_eq!(transfer.to_record(), "DE123456.000042  Ann  EUR..");
+    assert_eq!(Transfer::parse(&transfer.to_record()).unwrap(), transfer);
+
+    assert_eq!(Pair(*astr!("xy"), 7).to_record(), "xy7__");
+    assert_eq!(Empty {}.to_record(), "");
+}
+
+#[test]
+fn test_to_record_overflow() {
+    assert!(matches!(
+        Pair(*astr!("xy"), -100).try_to_record(),
+        Err(FixedWidthError::Overflow {
+            field: "1",
+            width: 3
+        })
+    ));
+}
+
+#[test]
+fn test_zero_padded_roundtrip() {
+    for zeros in [
+        Zeros {
+            n: 10,
+            s: "7".into(),
+        },
+        Zeros {
+            n: 0,
+            s: String::new(),
+        },
+    ] {
+        let record = zeros.to_record();
+        assert_eq!(Zeros::parse(&record).unwrap(), zeros);
+    }
+    assert_eq!(
+        Zeros {
+            n: 10,
+            s: String::new()
+        }
+        .to_record(),
+        "000010000"
+    );
+}
@@ -0,0 +1,42 @@
+use astr::{astr, format_astr_exact, AStr};
+
+#[test]
+fn test_integers() {
+    for n in [i8::MIN, -1, 0, 7, i8::MAX] {
+        let s: AStr<4> = format_astr_exact!("{:04}", n: i8);
+        assert_eq!(s.as_str(), format!("{:04}", n));
+        let s: AStr<4> = format_astr_exact!("{:+04}", n.unsigned_abs() / 2: u8);
+        assert_eq!(s.as_str(), format!("{:+04}", n.unsigned_abs() / 2));
+        let s: AStr<4> = format_astr_exact!("{:#04x}", n: i8);
+        assert_eq!(s.as_str(), format!("{:#04x}", n));
+    }
+    for n in [0, 1, u16::MAX] {
+        let s: AStr<22> = format_astr_exact!("{:<5}|{:0>16b}", n: u16, &n: &u16);
+        assert_eq!(s.as_str(), format!("{:<5}|{:0>16b}", n, n));
+    }
+    let s = format_astr_exact!("{:39}", u128::MAX: u128);
+    assert_eq!(s.len(), 39);
+    let s = format_astr_exact!("{:40}", i128::MIN: i128);
+    assert_eq!(s.len(), 40);
+}
+
+#[test]
+fn test_bool_and_astr() {
+    let s: AStr<12> =
+        format_astr_exact!("{:>5}|{:.1}|{}", true: bool, false: bool, astr!("äbc"): &AStr<4>);
+    assert_eq!(s, " true|f|äbc");
+
+    let a = *astr!("ä");
+    let s: AStr<9> = format_astr_exact!("[{:1}]{1:.3}{0:.2}", a: AStr<2>, false: bool);
+    assert_eq!(s, "[ä]falä");
+}
+
+#[test]
+fn test_literal_and_escapes() {
+    let s: AStr<0> = format_astr_exact!("");
+    assert_eq!(s, "");
+    let s: AStr<8> = format_astr_exact!("{{ä}} {:3}", 1: u8,);
+    assert_eq!(s, "{ä}   1");
+    let s: AStr<6> = format_astr_exact!("{:ä^3}", AStr::<0>::default(): AStr<0>);
+    assert_eq!(s, "äää");
+}
@@ -0,0 +1,68 @@
+use astr::{astr, AStr, AStrError, AStrNewtype, FixedStr};
+use serde::{
+    de::{value::Error, IntoDeserializer},
+    Deserialize,
+};
+
+fn digits(s: &str) -> Result<(), &'static str> {
+    if s.bytes().all(|b| b.is_ascii_digit()) {
+        Ok(())
+    } else {
+        Err("expected digits")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, AStrNewtype)]
+#[astr(validate = digits)]
+struct Sku(AStr<4>);
+
+#[derive(Clone, Copy, PartialEq, Eq, AStrNewtype)]
+struct Code(AStr<2>);
+
+const INVALID: AStrError = AStrError::Invalid {
+    reason: "expected digits",
+};
+
+#[test]
+fn test_constructors() {
+    let sku: Sku = "0042".parse().unwrap();
+    assert_eq!(sku, "0042");
+    assert_eq!(Sku::try_from("0042"), Ok(sku));
+    assert_eq!(Sku::try_from(*astr!("0042")), Ok(sku));
+    assert_eq!(<Sku as FixedStr>::try_from_utf8(b"0042"), Ok(sku));
+    assert_eq!(Sku::LEN, 4);
+
+    assert_eq!("00x2".parse::<Sku>(), Err(INVALID));
+    assert_eq!(Sku::try_from(*astr!("00x2")), Err(INVALID));
+    assert_eq!(<Sku as FixedStr>::try_from_utf8(b"00x2"), Err(INVALID));
+    assert!(matches!(
+        Sku::try_from("00042"),
+        Err(AStrError::Length { .. })
+    ));
+
+    assert_eq!(Code::try_new(*astr!("xy")).unwrap().into_inner(), "xy");
+}
+
+#[test]
+fn test_impls() {
+    let sku = Sku::try_new(*astr!("0815")).unwrap();
+    assert_eq!(sku.len(), 4);
+    assert_eq!(sku.to_string(), "0815");
+    assert_eq!(format!("{:?}", sku), r#"Sku("0815")"#);
+    assert_eq!(AsRef::<str>::as_ref(&sku), "0815");
+    assert_eq!(AsRef::<[u8]>::as_ref(&sku), b"0815");
+    assert_eq!(AStr::from(sku), "0815");
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(sku);
+    assert!(set.contains("0815"));
+}
+
+#[test]
+fn test_serde() {
+    let sku = Sku::deserialize("0042".into_deserializer()).map_err(|e: Error| e);
+    assert_eq!(sku.unwrap(), "0042");
+
+    let err = Sku::deserialize("00x2".into_deserializer()).map_err(|e: Error| e);
+    assert_eq!(err.unwrap_err().to_string(), INVALID.to_string());
+}
@@ -0,0 +1,98 @@
+//! Compares `format_astr!` with the builder it used before, which zero-filled its buffer and
+//! copied every piece through two `get_mut` calls.
+//!
+//! Run with `cargo bench --bench format`.
+
+use astr::{format_astr, AStr};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+mod old {
+    use astr::AStr;
+    use core::fmt::Write;
+
+    struct FmtBuilder<const CAP: usize> {
+        len: usize,
+        partial: AStr<CAP>,
+    }
+
+    impl<const CAP: usize> Write for FmtBuilder<CAP> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let offset = self.len;
+            let new_len = self.len.checked_add(s.len()).filter(|&len| len <= CAP);
+            let new_len = new_len.ok_or(core::fmt::Error)?;
+
+            let rest = self.partial.get_mut(offset..).ok_or(core::fmt::Error)?;
+            let rest_bounded = rest.get_mut(..s.len()).ok_or(core::fmt::Error)?;
+            // SAFETY: both are valid string slices of the same length
+            unsafe { rest_bounded.as_bytes_mut().copy_from_slice(s.as_bytes()) };
+            self.len = new_len;
+            Ok(())
+        }
+    }
+
+    pub fn try_from_fmt<const LEN: usize>(
+        display: impl core::fmt::Display,
+    ) -> Result<AStr<LEN>, core::fmt::Error> {
+        let mut builder = FmtBuilder {
+            len: 0,
+            partial: AStr::repeat('\0'),
+        };
+        write!(builder, "{}", display)?;
+        if builder.len == LEN {
+            Ok(builder.partial)
+        } else {
+            Err(core::fmt::Error)
+        }
+    }
+}
+
+fn bench_id(c: &mut Criterion) {
+    let mut group = c.benchmark_group("id");
+    group.bench_function("old", |b| {
+        b.iter(|| {
+            old::try_from_fmt::<11>(format_args!("{:06X}-{:04}", black_box(0xFA8072), 42)).unwrap()
+        })
+    });
+    group.bench_function("new", |b| {
+        b.iter(|| {
+            let id: AStr<11> = format_astr!("{:06X}-{:04}", black_box(0xFA8072), 42).unwrap();
+            id
+        })
+    });
+    group.finish();
+}
+
+fn bench_literal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("literal");
+    group.bench_function("old", |b| {
+        b.iter(|| {
+            old::try_from_fmt::<32>(format_args!("0123456789abcdef0123456789abcdef")).unwrap()
+        })
+    });
+    group.bench_function("new", |b| {
+        b.iter(|| {
+            let s: AStr<32> = format_astr!("0123456789abcdef0123456789abcdef").unwrap();
+            s
+        })
+    });
+    group.finish();
+}
+
+fn bench_overflow(c: &mut Criterion) {
+    let long = "x".repeat(4096);
+    let mut group = c.benchmark_group("overflow");
+    group.bench_function("old", |b| {
+        b.iter(|| old::try_from_fmt::<8>(format_args!("{}", black_box(&long))).unwrap_err())
+    });
+    group.bench_function("new", |b| {
+        b.iter(|| {
+            let err: Result<AStr<8>, _> = format_astr!("{}", black_box(&long));
+            err.unwrap_err()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_id, bench_literal, bench_overflow);
+criterion_main!(benches);
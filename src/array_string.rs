@@ -0,0 +1,504 @@
+use crate::{const_assert, AStr, AStrError};
+
+/// A str with a compile time capacity.
+///
+/// Where an [AStr] always holds exactly `LEN` bytes, an ArrayString holds anywhere between `0`
+/// and `CAP` bytes. The length is tracked at runtime, the storage stays on the stack.
+///
+/// ```rust
+/// use astr::ArrayString;
+///
+/// let mut s = ArrayString::<16>::new();
+/// s.push_str("hello");
+/// s.push(' ');
+/// s.push_str("world");
+/// assert_eq!(s, "hello world");
+/// assert_eq!(s.remaining_capacity(), 5);
+/// ```
+///
+/// An ArrayString of the right length converts into an [AStr].
+///
+/// ```rust
+/// use astr::{astr, AStr, ArrayString};
+///
+/// let s: ArrayString<5> = "hello".parse().unwrap();
+/// let a: AStr<5> = s.try_into().unwrap();
+/// assert_eq!(a, *astr!("hello"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayString<const CAP: usize> {
+    len: usize,
+    buf: [u8; CAP],
+}
+
+/// Error returned when an [ArrayString] has not enough capacity left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
+impl core::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+impl<const CAP: usize> ArrayString<CAP> {
+    /// Create a new empty ArrayString.
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            buf: [0; CAP],
+        }
+    }
+
+    /// Create a new ArrayString from a str.
+    pub fn try_from_str(s: &str) -> Result<Self, CapacityError> {
+        let mut ret = Self::new();
+        ret.try_push_str(s)?;
+        Ok(ret)
+    }
+
+    /// Create a new ArrayString from an [AStr].
+    ///
+    /// Fails to compile if `N` is larger than `CAP`.
+    pub const fn from_astr<const N: usize>(s: AStr<N>) -> Self {
+        const { const_assert(N <= CAP, "AStr does not fit into the ArrayString capacity") };
+
+        let bytes = s.as_bytes();
+        let mut buf = [0; CAP];
+        let mut i = 0;
+        while i < N {
+            buf[i] = bytes[i];
+            i += 1;
+        }
+        Self { len: N, buf }
+    }
+
+    /// Convert into an [AStr] of exactly `N` bytes.
+    pub fn to_astr<const N: usize>(&self) -> Result<AStr<N>, AStrError> {
+        AStr::try_from(self.as_str())
+    }
+
+    /// Returns the capacity in bytes.
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Returns the length in bytes.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the ArrayString holds no bytes.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the ArrayString holds `CAP` bytes.
+    pub const fn is_full(&self) -> bool {
+        self.len == CAP
+    }
+
+    /// Returns the number of bytes that can still be pushed.
+    pub const fn remaining_capacity(&self) -> usize {
+        CAP - self.len
+    }
+
+    /// get byte representation of the ArrayString
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.buf.split_at(self.len).0
+    }
+
+    /// get str representation of the ArrayString
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: the first `len` bytes are always valid UTF-8
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// get mutable str representation of the ArrayString
+    pub fn as_str_mut(&mut self) -> &mut str {
+        // SAFETY: the first `len` bytes are always valid UTF-8
+        unsafe { core::str::from_utf8_unchecked_mut(&mut self.buf[..self.len]) }
+    }
+
+    /// Append a char.
+    /// # Panics
+    /// Panics if there is not enough capacity left.
+    pub fn push(&mut self, c: char) {
+        self.try_push(c).unwrap()
+    }
+
+    /// Append a char.
+    pub fn try_push(&mut self, c: char) -> Result<(), CapacityError> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Append a str.
+    /// # Panics
+    /// Panics if there is not enough capacity left.
+    pub fn push_str(&mut self, s: &str) {
+        self.try_push_str(s).unwrap()
+    }
+
+    /// Append a str.
+    ///
+    /// Nothing is written if `s` does not fit.
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        let rest = self
+            .buf
+            .get_mut(self.len..)
+            .and_then(|rest| rest.get_mut(..s.len()))
+            .ok_or(CapacityError)?;
+        rest.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+
+    /// Remove the last char and return it.
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.len -= c.len_utf8();
+        Some(c)
+    }
+
+    /// Shorten the ArrayString to `new_len` bytes.
+    ///
+    /// Has no effect if `new_len` is greater than the current length.
+    /// # Panics
+    /// Panics if `new_len` does not lie on a char boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new_len is not a char boundary"
+            );
+            self.len = new_len;
+        }
+    }
+
+    /// Remove all contents.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const CAP: usize> Default for ArrayString<CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAP: usize> core::fmt::Write for ArrayString<CAP> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.try_push_str(s).map_err(|_| core::fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.try_push(c).map_err(|_| core::fmt::Error)
+    }
+}
+
+impl<const CAP: usize> FromIterator<char> for ArrayString<CAP> {
+    /// # Panics
+    /// Panics if the chars do not fit into `CAP` bytes.
+    fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<'a, const CAP: usize> FromIterator<&'a str> for ArrayString<CAP> {
+    /// # Panics
+    /// Panics if the strs do not fit into `CAP` bytes.
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        let mut ret = Self::new();
+        ret.extend(iter);
+        ret
+    }
+}
+
+impl<const CAP: usize> Extend<char> for ArrayString<CAP> {
+    /// # Panics
+    /// Panics if there is not enough capacity left.
+    fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|c| self.push(c))
+    }
+}
+
+impl<'a, const CAP: usize> Extend<&'a char> for ArrayString<CAP> {
+    /// # Panics
+    /// Panics if there is not enough capacity left.
+    fn extend<T: IntoIterator<Item = &'a char>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|c| self.push(*c))
+    }
+}
+
+impl<'a, const CAP: usize> Extend<&'a str> for ArrayString<CAP> {
+    /// # Panics
+    /// Panics if there is not enough capacity left.
+    fn extend<T: IntoIterator<Item = &'a str>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|s| self.push_str(s))
+    }
+}
+
+impl<const CAP: usize> From<AStr<CAP>> for ArrayString<CAP> {
+    fn from(s: AStr<CAP>) -> Self {
+        Self::from_astr(s)
+    }
+}
+
+impl<const CAP: usize> TryFrom<ArrayString<CAP>> for AStr<CAP> {
+    type Error = AStrError;
+
+    fn try_from(s: ArrayString<CAP>) -> Result<Self, Self::Error> {
+        s.to_astr()
+    }
+}
+
+impl<const CAP: usize> TryFrom<&'_ str> for ArrayString<CAP> {
+    type Error = CapacityError;
+
+    fn try_from(s: &'_ str) -> Result<Self, Self::Error> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const CAP: usize> core::str::FromStr for ArrayString<CAP> {
+    type Err = CapacityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+impl<const CAP: usize> AsRef<str> for ArrayString<CAP> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> AsMut<str> for ArrayString<CAP> {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_str_mut()
+    }
+}
+
+impl<const CAP: usize> AsRef<[u8]> for ArrayString<CAP> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const CAP: usize> core::borrow::Borrow<str> for ArrayString<CAP> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> core::borrow::BorrowMut<str> for ArrayString<CAP> {
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_str_mut()
+    }
+}
+
+impl<const CAP: usize> core::ops::Deref for ArrayString<CAP> {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> core::ops::DerefMut for ArrayString<CAP> {
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_str_mut()
+    }
+}
+
+impl<const CAP: usize> core::fmt::Debug for ArrayString<CAP> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const CAP: usize> core::fmt::Display for ArrayString<CAP> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const CAP: usize> core::hash::Hash for ArrayString<CAP> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const CAP: usize> PartialEq for ArrayString<CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq(other.as_str())
+    }
+}
+
+impl<const CAP: usize> Eq for ArrayString<CAP> {}
+
+impl<const CAP: usize> PartialOrd for ArrayString<CAP> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const CAP: usize> Ord for ArrayString<CAP> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const CAP: usize> PartialEq<str> for ArrayString<CAP> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(other)
+    }
+}
+
+impl<const CAP: usize> PartialEq<&'_ str> for ArrayString<CAP> {
+    fn eq(&self, other: &&'_ str) -> bool {
+        self.eq(*other)
+    }
+}
+
+impl<const CAP: usize> PartialEq<ArrayString<CAP>> for str {
+    fn eq(&self, other: &ArrayString<CAP>) -> bool {
+        self.eq(other.as_str())
+    }
+}
+
+impl<const CAP: usize> PartialEq<ArrayString<CAP>> for &'_ str {
+    fn eq(&self, other: &ArrayString<CAP>) -> bool {
+        (*self).eq(other)
+    }
+}
+
+impl<const CAP: usize, const LEN: usize> PartialEq<AStr<LEN>> for ArrayString<CAP> {
+    fn eq(&self, other: &AStr<LEN>) -> bool {
+        self.as_str().eq(other.as_str())
+    }
+}
+
+impl<const CAP: usize, const LEN: usize> PartialEq<ArrayString<CAP>> for AStr<LEN> {
+    fn eq(&self, other: &ArrayString<CAP>) -> bool {
+        self.as_str().eq(other.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const CAP: usize> From<ArrayString<CAP>> for alloc::string::String {
+    fn from(s: ArrayString<CAP>) -> Self {
+        s.as_str().into()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::ArrayString;
+    use serde::{
+        de::{self, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    impl<const CAP: usize> Serialize for ArrayString<CAP> {
+        fn serialize<S: Serializer>(&'_ self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    struct ArrayStringVisitor<const CAP: usize>;
+
+    impl<'de, const CAP: usize> Visitor<'de> for ArrayStringVisitor<CAP> {
+        type Value = ArrayString<CAP>;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(formatter, "a string of at most {} bytes", CAP)
+        }
+
+        #[inline]
+        fn visit_str<E: de::Error>(self, s: &'_ str) -> Result<Self::Value, E> {
+            ArrayString::try_from_str(s).map_err(|_| de::Error::invalid_length(s.len(), &self))
+        }
+    }
+
+    impl<'de, const CAP: usize> Deserialize<'de> for ArrayString<CAP> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_str(ArrayStringVisitor::<CAP>)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArrayString, CapacityError};
+    use crate::AStr;
+
+    #[test]
+    fn test_push() {
+        let mut s = ArrayString::<8>::new();
+        s.push_str("abc");
+        s.push('ä');
+        assert_eq!(s, "abcä");
+        assert_eq!(s.len(), 5);
+        assert_eq!(s.try_push_str("defg"), Err(CapacityError));
+        assert_eq!(s, "abcä");
+        s.push_str("def");
+        assert!(s.is_full());
+    }
+
+    #[test]
+    fn test_pop_truncate_clear() {
+        let mut s: ArrayString<8> = "abä".parse().unwrap();
+        assert_eq!(s.pop(), Some('ä'));
+        assert_eq!(s, "ab");
+        s.truncate(1);
+        assert_eq!(s, "a");
+        s.clear();
+        assert!(s.is_empty());
+        assert_eq!(s.pop(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_truncate_char_boundary() {
+        let mut s: ArrayString<8> = "ä".parse().unwrap();
+        s.truncate(1);
+    }
+
+    #[test]
+    fn test_collect() {
+        let s: ArrayString<5> = "hello".chars().collect();
+        assert_eq!(s, "hello");
+
+        let mut s: ArrayString<10> = ["ab", "cd"].into_iter().collect();
+        s.extend(['e', 'f']);
+        assert_eq!(s, "abcdef");
+    }
+
+    #[test]
+    fn test_fmt_write() {
+        use core::fmt::Write;
+        let mut s = ArrayString::<6>::new();
+        write!(s, "{:06X}", 0xFA8072u32).unwrap();
+        assert_eq!(s, "FA8072");
+        assert!(write!(s, "!").is_err());
+    }
+
+    #[test]
+    fn test_astr_conversion() {
+        let a = *crate::astr!("hello");
+        let s = ArrayString::<8>::from_astr(a);
+        assert_eq!(s, a);
+        assert_eq!(s.to_astr::<5>().unwrap(), a);
+        assert!(s.to_astr::<8>().is_err());
+
+        let s = ArrayString::from(a);
+        let b: AStr<5> = s.try_into().unwrap();
+        assert_eq!(a, b);
+    }
+}
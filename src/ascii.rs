@@ -0,0 +1,169 @@
+use crate::{
+    validate::{Ascii, Validator},
+    AStr, AStrError, Validated,
+};
+
+/// An [AStr] that only holds ASCII, so every byte is a char.
+///
+/// This is a [Validated] with the [Ascii] validator, the ASCII methods index chars in O(1) and
+/// change them in place without `unsafe`.
+///
+/// ```rust
+/// use astr::{ascii_astr, AsciiAStr};
+///
+/// let mut code: AsciiAStr<6> = ascii_astr!("de-042");
+/// code.make_ascii_uppercase();
+/// code.set(3, '1').unwrap();
+/// assert_eq!(code, "DE-142");
+/// assert_eq!(code.get(1), 'E');
+/// assert!(code.set(0, 'Ä').is_err());
+/// ```
+pub type AsciiAStr<const LEN: usize> = Validated<LEN, Ascii>;
+
+impl<const LEN: usize> AsciiAStr<LEN> {
+    /// Get the char at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub const fn get(&self, index: usize) -> char {
+        self.as_astr().as_bytes()[index] as char
+    }
+
+    /// Replace the char at `index`, `c` can be a `char` or a `u8`.
+    ///
+    /// # Errors
+    /// Returns [`AStrError::Invalid`] and leaves the str unchanged if `c` is not ASCII.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, c: impl Into<char>) -> Result<(), AStrError> {
+        let c = c.into();
+        if !c.is_ascii() {
+            return Err(AStrError::Invalid {
+                reason: Ascii::REASON,
+            });
+        }
+        // SAFETY: an ASCII byte keeps the str ASCII
+        unsafe { self.as_astr_mut().as_bytes_mut()[index] = c as u8 };
+        Ok(())
+    }
+
+    /// Convert all ASCII letters to upper case in place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        // SAFETY: case mapping maps ASCII to ASCII
+        unsafe { self.as_astr_mut().0.make_ascii_uppercase() }
+    }
+
+    /// Convert all ASCII letters to lower case in place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        // SAFETY: case mapping maps ASCII to ASCII
+        unsafe { self.as_astr_mut().0.make_ascii_lowercase() }
+    }
+
+    /// Iterate over the bytes, each of them is a char.
+    pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, u8>> {
+        self.as_astr().as_bytes().iter().copied()
+    }
+}
+
+impl<const LEN: usize> AStr<LEN> {
+    /// Convert to an [AsciiAStr], checking that all chars are ASCII.
+    ///
+    /// ```rust
+    /// use astr::astr;
+    ///
+    /// assert_eq!(astr!("Kase").try_into_ascii().unwrap().get(1), 'a');
+    /// assert!(astr!("Käse").try_into_ascii().is_err());
+    /// ```
+    pub const fn try_into_ascii(self) -> Result<AsciiAStr<LEN>, AStrError> {
+        if self.is_ascii() {
+            // SAFETY: checked above
+            Ok(unsafe { AsciiAStr::new_unchecked(self) })
+        } else {
+            Err(AStrError::Invalid {
+                reason: Ascii::REASON,
+            })
+        }
+    }
+}
+
+impl<const LEN: usize> IntoIterator for AsciiAStr<LEN> {
+    type Item = u8;
+    type IntoIter = core::array::IntoIter<u8, LEN>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inner().0.into_iter()
+    }
+}
+
+/// Build an [AsciiAStr] from a literal, checked at compile time.
+///
+/// ```rust
+/// use astr::ascii_astr;
+///
+/// let s = ascii_astr!("BER");
+/// assert_eq!(s.get(2), 'R');
+/// ```
+///
+/// Literals with non ASCII chars fail to compile.
+///
+/// ```rust,compile_fail
+/// use astr::ascii_astr;
+///
+/// let s = ascii_astr!("BÄR");
+/// ```
+#[macro_export]
+macro_rules! ascii_astr {
+    ($input:expr) => {
+        $crate::validated!($crate::validate::Ascii, $input)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsciiAStr;
+    use crate::{astr, AStr, AStrError};
+
+    #[test]
+    fn test_get_set() {
+        let mut s: AsciiAStr<4> = crate::ascii_astr!("abcd");
+        assert_eq!([0, 1, 2, 3].map(|i| s.get(i)), ['a', 'b', 'c', 'd']);
+        s.set(0, b'x').unwrap();
+        s.set(3, '!').unwrap();
+        assert_eq!(s, "xbc!");
+        let invalid = Err(AStrError::Invalid {
+            reason: "expected ASCII characters",
+        });
+        assert_eq!(s.set(1, 'ä'), invalid);
+        assert_eq!(s.set(1, 0xe4), invalid);
+        assert_eq!(s, "xbc!");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_out_of_bounds() {
+        let mut s = crate::ascii_astr!("ab");
+        let _ = s.set(2, 'c');
+    }
+
+    #[test]
+    fn test_case_and_iter() {
+        let mut s = crate::ascii_astr!("Ab-1");
+        s.make_ascii_uppercase();
+        assert_eq!(s, "AB-1");
+        s.make_ascii_lowercase();
+        assert_eq!(s, "ab-1");
+        assert!(s.iter().eq(*b"ab-1"));
+        assert_eq!(s.into_iter().rev().collect::<Vec<u8>>(), b"1-ba");
+    }
+
+    #[test]
+    fn test_conversions() {
+        let s = astr!("ABC").try_into_ascii().unwrap();
+        let back: AStr<3> = s.into();
+        assert_eq!(back, "ABC");
+        assert!(astr!("ÄB").try_into_ascii().is_err());
+        assert_eq!("xyz".parse::<AsciiAStr<3>>().unwrap(), "xyz");
+        assert!("xyä".parse::<AsciiAStr<4>>().is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{AStr, AStrError, FmtErrorKind};
+use core::mem::MaybeUninit;
+
+/// Build an [AStr] piece by piece.
+///
+/// Pieces that do not fit are rejected and mark the builder as overflowed, [`finish`] then
+/// reports the overflow. This way a sequence of pushes only needs a single check at the end.
+///
+/// ```rust
+/// use astr::{astr, AStr, AStrBuilder};
+/// use core::fmt::Write;
+///
+/// let mut builder = AStrBuilder::<9>::new();
+/// builder.push_str("ID");
+/// write!(builder, "{:06}", 4711).unwrap();
+/// builder.push('7');
+/// assert_eq!(builder.remaining(), 0);
+///
+/// let code: AStr<9> = builder.finish().unwrap();
+/// assert_eq!(code, "ID0047117");
+/// ```
+///
+/// [`finish`]: AStrBuilder::finish
+#[derive(Clone, Copy)]
+pub struct AStrBuilder<const N: usize> {
+    len: usize,
+    overflow: bool,
+    /// The first `len` bytes are initialized and valid UTF-8.
+    buf: [MaybeUninit<u8>; N],
+}
+
+impl<const N: usize> AStrBuilder<N> {
+    /// Create a new empty builder.
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            overflow: false,
+            buf: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+
+    /// Returns the number of bytes written so far.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing was written yet.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes left to fill.
+    pub const fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    /// Returns `true` if a piece did not fit.
+    pub const fn is_overflowed(&self) -> bool {
+        self.overflow
+    }
+
+    /// Get the str written so far.
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: the first `len` bytes are initialized and valid UTF-8
+        unsafe {
+            let bytes = core::slice::from_raw_parts(self.buf.as_ptr().cast::<u8>(), self.len);
+            core::str::from_utf8_unchecked(bytes)
+        }
+    }
+
+    /// Append a str.
+    ///
+    /// If `s` does not fit, nothing is written and the builder is marked as overflowed.
+    pub fn push_str(&mut self, s: &str) -> &mut Self {
+        if self.overflow || s.len() > self.remaining() {
+            self.overflow = true;
+        } else {
+            // SAFETY: `s` fits into the uninitialized rest of `buf`, which can not overlap `s`
+            unsafe {
+                let dest = self.buf.as_mut_ptr().add(self.len).cast::<u8>();
+                core::ptr::copy_nonoverlapping(s.as_ptr(), dest, s.len());
+            }
+            self.len += s.len();
+        }
+        self
+    }
+
+    /// Append a char.
+    ///
+    /// If `c` does not fit, nothing is written and the builder is marked as overflowed.
+    pub fn push(&mut self, c: char) -> &mut Self {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Append an [AStr].
+    ///
+    /// If `s` does not fit, nothing is written and the builder is marked as overflowed.
+    pub fn push_astr<const LEN: usize>(&mut self, s: &AStr<LEN>) -> &mut Self {
+        self.push_str(s.as_str())
+    }
+
+    /// Get the finished [AStr].
+    ///
+    /// # Error
+    ///
+    /// Returns an [`AStrError::Fmt`] if a piece did not fit or less than `N` bytes were written.
+    pub fn finish(self) -> Result<AStr<N>, AStrError> {
+        self.finish_fmt(Ok(()))
+    }
+
+    /// Like [`finish`](Self::finish), but also reports the result of the formatting that wrote
+    /// into the builder.
+    pub(crate) fn finish_fmt(self, result: core::fmt::Result) -> Result<AStr<N>, AStrError> {
+        let kind = match result {
+            _ if self.overflow => FmtErrorKind::Overflow,
+            Err(_) => FmtErrorKind::Error,
+            // SAFETY: all `N` bytes are initialized and valid UTF-8
+            Ok(()) if self.len == N => return Ok(unsafe { self.assume_init() }),
+            Ok(()) => FmtErrorKind::TooShort { written: self.len },
+        };
+        Err(AStrError::Fmt {
+            expected_len: N,
+            kind,
+        })
+    }
+
+    /// # Safety
+    /// All `N` bytes must be written.
+    const unsafe fn assume_init(&self) -> AStr<N> {
+        AStr(self.buf.as_ptr().cast::<[u8; N]>().read())
+    }
+}
+
+impl<const N: usize> Default for AStrBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> core::fmt::Write for AStrBuilder<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if self.push_str(s).overflow {
+            Err(core::fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for AStrBuilder<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AStrBuilder")
+            .field("partial", &self.as_str())
+            .field("overflow", &self.overflow)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AStrBuilder;
+    use crate::{AStr, AStrError, FmtErrorKind};
+    use core::fmt::Write;
+
+    #[test]
+    fn test_builder() {
+        let mut builder = AStrBuilder::<8>::new();
+        assert!(builder.is_empty());
+        builder
+            .push_str("ab")
+            .push('ä')
+            .push_astr(crate::astr!("cde"));
+        assert_eq!(builder.as_str(), "abäcde");
+        assert_eq!(builder.remaining(), 1);
+        builder.push('f');
+        assert_eq!(builder.finish().unwrap(), "abäcdef");
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        let mut builder = AStrBuilder::<4>::new();
+        builder.push_str("abc");
+        assert_eq!(
+            builder.finish(),
+            Err(AStrError::Fmt {
+                expected_len: 4,
+                kind: FmtErrorKind::TooShort { written: 3 }
+            })
+        );
+
+        builder.push('ä').push('d');
+        assert!(builder.is_overflowed());
+        assert_eq!(builder.as_str(), "abc");
+        assert_eq!(
+            builder.finish(),
+            Err(AStrError::Fmt {
+                expected_len: 4,
+                kind: FmtErrorKind::Overflow
+            })
+        );
+        assert!(write!(builder, "x").is_err());
+    }
+
+    #[test]
+    fn test_try_from_chars() {
+        assert_eq!(AStr::<4>::try_from_chars("abä".chars()).unwrap(), "abä");
+        assert_eq!(
+            AStr::<4>::try_from_chars(core::iter::repeat('a')),
+            Err(AStrError::Fmt {
+                expected_len: 4,
+                kind: FmtErrorKind::Overflow
+            })
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use crate::AStr;
+use core::cmp::Ordering;
+
+const fn bytes_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return if a[i] < b[i] {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        i += 1;
+    }
+    if a.len() < b.len() {
+        Ordering::Less
+    } else if a.len() > b.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    matches!(bytes_cmp(a, b), Ordering::Equal)
+}
+
+/// `const` versions of the common `str` methods.
+///
+/// The `str` methods reachable through `Deref` can not be called in `const` context.
+/// [`is_ascii`](Self::is_ascii) and [`eq_ignore_ascii_case`](Self::eq_ignore_ascii_case) have
+/// the same signature as their `str` versions and keep the name. The others take different
+/// arguments or return an [AStr], they are prefixed with `const_` or suffixed with `_astr` so
+/// they don't shadow the `str` methods. [`find_byte`](Self::find_byte) and
+/// [`char_count`](Self::char_count) have no `str` counterpart. Patterns are always a `&str`, the
+/// `str` methods accept the others.
+///
+/// ```rust
+/// use astr::{astr, AStr};
+///
+/// const CODE: AStr<6> = *astr!("DE-042");
+/// const _: () = assert!(CODE.const_starts_with("DE") && CODE.is_ascii());
+/// const LOWER: AStr<6> = CODE.to_ascii_lowercase_astr();
+/// const _: () = assert!(LOWER.const_eq(astr!("de-042")));
+/// ```
+impl<const LEN: usize> AStr<LEN> {
+    /// Returns `true` if both strs are equal, `other` can have any length.
+    pub const fn const_eq<const B_LEN: usize>(&self, other: &AStr<B_LEN>) -> bool {
+        bytes_eq(&self.0, &other.0)
+    }
+
+    /// Compares the strs lexicographically by bytes like `str::cmp`, `other` can have any
+    /// length.
+    pub const fn const_cmp<const B_LEN: usize>(&self, other: &AStr<B_LEN>) -> Ordering {
+        bytes_cmp(&self.0, &other.0)
+    }
+
+    /// Returns `true` if the str starts with `prefix`.
+    pub const fn const_starts_with(&self, prefix: &str) -> bool {
+        prefix.len() <= LEN && bytes_eq(self.0.split_at(prefix.len()).0, prefix.as_bytes())
+    }
+
+    /// Returns `true` if the str ends with `suffix`.
+    pub const fn const_ends_with(&self, suffix: &str) -> bool {
+        suffix.len() <= LEN && bytes_eq(self.0.split_at(LEN - suffix.len()).1, suffix.as_bytes())
+    }
+
+    /// Returns the index of the first occurrence of `byte`.
+    pub const fn find_byte(&self, byte: u8) -> Option<usize> {
+        let mut i = 0;
+        while i < LEN {
+            if self.0[i] == byte {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns `true` if `needle` is a substring.
+    pub const fn const_contains(&self, needle: &str) -> bool {
+        let needle = needle.as_bytes();
+        let mut start = 0;
+        while start + needle.len() <= LEN {
+            let candidate = self.0.split_at(start).1.split_at(needle.len()).0;
+            if bytes_eq(candidate, needle) {
+                return true;
+            }
+            start += 1;
+        }
+        false
+    }
+
+    /// Returns `true` if all chars are ASCII.
+    pub const fn is_ascii(&self) -> bool {
+        self.0.is_ascii()
+    }
+
+    /// Returns `true` if both strs are equal, ignoring ASCII case.
+    pub const fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        let other = other.as_bytes();
+        if other.len() != LEN {
+            return false;
+        }
+        let mut i = 0;
+        while i < LEN {
+            if !self.0[i].eq_ignore_ascii_case(&other[i]) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns a copy with all ASCII letters in upper case, other chars are unchanged.
+    pub const fn to_ascii_uppercase_astr(&self) -> Self {
+        let mut ret = *self;
+        ret.0.make_ascii_uppercase();
+        ret
+    }
+
+    /// Returns a copy with all ASCII letters in lower case, other chars are unchanged.
+    pub const fn to_ascii_lowercase_astr(&self) -> Self {
+        let mut ret = *self;
+        ret.0.make_ascii_lowercase();
+        ret
+    }
+
+    /// Returns the number of chars, `self.chars().count()` in `const`.
+    pub const fn char_count(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < LEN {
+            // count every byte that is not a continuation byte
+            if self.0[i] & 0xC0 != 0x80 {
+                count += 1;
+            }
+            i += 1;
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{astr, AStr};
+    use core::cmp::Ordering;
+
+    #[test]
+    fn test_const_cmp() {
+        const A: &AStr<3> = astr!("abc");
+        const _: () = assert!(A.const_eq(astr!("abc")) && !A.const_eq(astr!("ab")));
+        const CMP: [Ordering; 4] = [
+            A.const_cmp(astr!("abd")),
+            A.const_cmp(astr!("ab")),
+            A.const_cmp(astr!("abc")),
+            A.const_cmp(astr!("b")),
+        ];
+        assert_eq!(
+            CMP,
+            [
+                Ordering::Less,
+                Ordering::Greater,
+                Ordering::Equal,
+                Ordering::Less
+            ]
+        );
+        assert_eq!(A.const_cmp(astr!("")), "abc".cmp(""));
+    }
+
+    #[test]
+    fn test_const_search() {
+        const S: &AStr<9> = astr!("Käsebrot");
+        const _: () = assert!(
+            S.const_starts_with("Kä") && S.const_starts_with("") && !S.const_starts_with("ä")
+        );
+        const _: () = assert!(S.const_ends_with("brot") && !S.const_ends_with("Käsebrot!"));
+        const _: () =
+            assert!(S.const_contains("sebr") && S.const_contains("") && !S.const_contains("brotz"));
+        assert_eq!(S.find_byte(b's'), Some(3));
+        assert_eq!(S.find_byte(b'x'), None);
+        assert_eq!(S.char_count(), 8);
+        assert_eq!(astr!("").char_count(), 0);
+    }
+
+    #[test]
+    fn test_const_ascii() {
+        const S: AStr<5> = *astr!("Käse");
+        const UPPER: AStr<5> = S.to_ascii_uppercase_astr();
+        const LOWER: AStr<5> = S.to_ascii_lowercase_astr();
+        assert_eq!(UPPER, "KäSE");
+        assert_eq!(LOWER, "käse");
+        assert!(!S.is_ascii() && astr!("Kase").is_ascii());
+        assert!(S.eq_ignore_ascii_case("kÄSE") == "Käse".eq_ignore_ascii_case("kÄSE"));
+        assert!(S.eq_ignore_ascii_case("KÄSE") == "Käse".eq_ignore_ascii_case("KÄSE"));
+        assert!(UPPER.eq_ignore_ascii_case("käse"));
+    }
+
+    #[test]
+    fn test_str_methods_not_shadowed() {
+        let s = astr!("Käse");
+        assert!(s.contains('s') && s.contains(char::is_alphabetic));
+        assert!(s.starts_with(char::is_uppercase) && s.ends_with(['e', 'x']));
+        assert!(s.eq("Käse"));
+        let upper: String = s.to_ascii_uppercase();
+        assert_eq!(upper, "KäSE");
+    }
+}
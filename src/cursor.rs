@@ -0,0 +1,205 @@
+use crate::{is_char_boundary, AStr, AStrError};
+
+impl<const LEN: usize> AStr<LEN> {
+    /// Replace the bytes from `offset` with `s`, the rest of the str is unchanged.
+    ///
+    /// ```rust
+    /// use astr::{astr, AStrError};
+    ///
+    /// let mut record = *astr!("0042Berlin ");
+    /// record.overwrite_at(4, "Bremen").unwrap();
+    /// assert_eq!(record, "0042Bremen ");
+    ///
+    /// let mut s = *astr!("Käse");
+    /// assert_eq!(s.overwrite_at(2, "x"), Err(AStrError::CharBoundary { index: 2 }));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns [`AStrError::Length`] with the needed length if `s` does not fit and
+    /// [`AStrError::CharBoundary`] if the start or the end of the overwritten bytes is inside
+    /// a char. The str is unchanged on error.
+    pub const fn overwrite_at(&mut self, offset: usize, s: &str) -> Result<(), AStrError> {
+        let end = match offset.checked_add(s.len()) {
+            Some(end) if end <= LEN => end,
+            _ => return Err(AStrError::length(LEN, offset.saturating_add(s.len()))),
+        };
+        if !is_char_boundary(&self.0, offset) {
+            return Err(AStrError::CharBoundary { index: offset });
+        }
+        if !is_char_boundary(&self.0, end) {
+            return Err(AStrError::CharBoundary { index: end });
+        }
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self.0[offset + i] = bytes[i];
+            i += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Writes into an existing [AStr] from a position on, overwriting what was there.
+///
+/// Every write goes through [`AStr::overwrite_at`] and is done completely or not at all, so
+/// writes have to fit and end on a char boundary of the existing content.
+///
+/// ```rust
+/// use astr::{astr, AStrCursor};
+/// use std::fmt::Write;
+///
+/// let mut record = *astr!("id=____;ok");
+/// let mut cursor = AStrCursor::new(&mut record);
+/// cursor.set_position(3).unwrap();
+/// write!(cursor, "{:04}", 42).unwrap();
+/// assert_eq!(cursor.position(), 7);
+/// assert!(write!(cursor, "{}", "too long").is_err());
+/// assert_eq!(record, "id=0042;ok");
+/// ```
+#[derive(Debug)]
+pub struct AStrCursor<'a, const N: usize> {
+    inner: &'a mut AStr<N>,
+    pos: usize,
+}
+
+impl<'a, const N: usize> AStrCursor<'a, N> {
+    /// Create a new cursor at the start of the str.
+    pub const fn new(inner: &'a mut AStr<N>) -> Self {
+        Self { inner, pos: 0 }
+    }
+
+    /// Byte offset of the next write.
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Move the cursor, `pos` must be a char boundary.
+    pub const fn set_position(&mut self, pos: usize) -> Result<(), AStrError> {
+        if pos > N {
+            return Err(AStrError::length(N, pos));
+        }
+        if !is_char_boundary(&self.inner.0, pos) {
+            return Err(AStrError::CharBoundary { index: pos });
+        }
+        self.pos = pos;
+        Ok(())
+    }
+
+    /// Number of bytes after the position.
+    pub const fn remaining(&self) -> usize {
+        N - self.pos
+    }
+
+    /// Overwrite the bytes at the position with `s` and move the position past them.
+    pub const fn write_str(&mut self, s: &str) -> Result<(), AStrError> {
+        match self.inner.overwrite_at(self.pos, s) {
+            Ok(()) => {
+                self.pos += s.len();
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    pub const fn get_ref(&self) -> &AStr<N> {
+        self.inner
+    }
+
+    pub const fn into_inner(self) -> &'a mut AStr<N> {
+        self.inner
+    }
+}
+
+impl<const N: usize> core::fmt::Write for AStrCursor<'_, N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        AStrCursor::write_str(self, s).map_err(|_| core::fmt::Error)
+    }
+}
+
+/// Writes have to be valid UTF-8 on their own, a char split over two writes is rejected.
+#[cfg(feature = "std")]
+impl<const N: usize> std::io::Write for AStrCursor<'_, N> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        use std::io::{Error, ErrorKind};
+
+        let s = core::str::from_utf8(buf).map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+        match AStrCursor::write_str(self, s) {
+            Ok(()) => Ok(buf.len()),
+            Err(err @ AStrError::Length { .. }) => Err(Error::new(ErrorKind::WriteZero, err)),
+            Err(err) => Err(Error::new(ErrorKind::InvalidInput, err)),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AStrCursor;
+    use crate::{astr, AStrError};
+
+    #[test]
+    fn test_overwrite_at() {
+        let mut s = *astr!("aäb");
+        s.overwrite_at(1, "xy").unwrap();
+        assert_eq!(s, "axyb");
+        s.overwrite_at(4, "").unwrap();
+        s.overwrite_at(0, "ö").unwrap();
+        assert_eq!(s, "öyb");
+        assert_eq!(
+            s.overwrite_at(3, "cd"),
+            Err(AStrError::Length {
+                expected_len: 4,
+                actual_len: 5
+            })
+        );
+        assert_eq!(
+            s.overwrite_at(1, "c"),
+            Err(AStrError::CharBoundary { index: 1 })
+        );
+        assert_eq!(
+            s.overwrite_at(0, "c"),
+            Err(AStrError::CharBoundary { index: 1 })
+        );
+        assert!(s.overwrite_at(usize::MAX, "c").is_err());
+        assert_eq!(s, "öyb");
+    }
+
+    #[test]
+    fn test_cursor_fmt() {
+        use core::fmt::Write;
+
+        let mut s = *astr!("[    ]");
+        let mut cursor = AStrCursor::new(&mut s);
+        assert_eq!(cursor.set_position(7), Err(AStrError::length(6, 7)));
+        cursor.set_position(1).unwrap();
+        let c = 'ä';
+        write!(cursor, "{}{c}", 1).unwrap();
+        assert_eq!(cursor.remaining(), 2);
+        assert!(write!(cursor, "abc").is_err());
+        assert_eq!(cursor.position(), 4);
+        cursor.write_char('!').unwrap();
+        assert_eq!(cursor.into_inner(), "[1ä!]");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_cursor_io() {
+        use std::io::{ErrorKind, Write};
+
+        let mut s = *astr!("ab-äö");
+        let mut cursor = AStrCursor::new(&mut s);
+        cursor.write_all(b"xy-").unwrap();
+        let err = cursor.write_all(&"ü".as_bytes()[..1]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let err = cursor.write_all(b"u").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        let rest = "üö";
+        write!(cursor, "{rest}").unwrap();
+        let err = cursor.write_all(b"!").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+        assert_eq!(cursor.get_ref(), "xy-üö");
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{AStr, AStrError};
+
+/// The object safe part of [FixedStr], use `&dyn DynFixedStr` to mix strs of different lengths.
+///
+/// ```rust
+/// use astr::{astr, DynFixedStr};
+///
+/// let codes: [&dyn DynFixedStr; 2] = [astr!("DE"), astr!("AUT")];
+/// let joined: Vec<&str> = codes.iter().map(|c| c.as_str()).collect();
+/// assert_eq!(joined, ["DE", "AUT"]);
+/// ```
+pub trait DynFixedStr {
+    /// Get the str.
+    fn as_str(&self) -> &str;
+
+    /// Get the bytes of the str.
+    fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+/// A str of a fixed length, implemented by every [AStr] and usable for newtypes around one.
+///
+/// ```rust
+/// use astr::{AStr, AStrError, FixedStr};
+///
+/// fn parse_all<T: FixedStr>(input: &str) -> Result<Vec<T>, AStrError> {
+///     input.split(',').map(T::try_from_str).collect()
+/// }
+///
+/// let codes: Vec<AStr<3>> = parse_all("BER,HAM").unwrap();
+/// assert_eq!(codes, ["BER", "HAM"]);
+/// assert!(parse_all::<AStr<3>>("BER,HH").is_err());
+/// ```
+pub trait FixedStr: DynFixedStr + Sized {
+    /// Length of the str in bytes.
+    const LEN: usize;
+
+    /// Create a new value from a str of exactly `LEN` bytes.
+    fn try_from_str(s: &str) -> Result<Self, AStrError>;
+
+    /// Create a new value from exactly `LEN` bytes of valid UTF-8.
+    fn try_from_utf8(bytes: &[u8]) -> Result<Self, AStrError>;
+}
+
+impl<const LEN: usize> DynFixedStr for AStr<LEN> {
+    fn as_str(&self) -> &str {
+        AStr::as_str(self)
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        AStr::as_bytes(self)
+    }
+}
+
+impl<const LEN: usize> FixedStr for AStr<LEN> {
+    const LEN: usize = LEN;
+
+    fn try_from_str(s: &str) -> Result<Self, AStrError> {
+        AStr::try_from_str_ref(s).copied()
+    }
+
+    fn try_from_utf8(bytes: &[u8]) -> Result<Self, AStrError> {
+        AStr::try_from_utf8(bytes).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynFixedStr, FixedStr};
+    use crate::{AStr, AStrError};
+
+    fn roundtrip<T: FixedStr>(s: &str) -> Result<T, AStrError> {
+        let value = T::try_from_str(s)?;
+        assert_eq!(value.as_str(), s);
+        assert_eq!(T::LEN, s.len());
+        T::try_from_utf8(value.as_bytes())
+    }
+
+    #[test]
+    fn test_fixed_str() {
+        let s: AStr<4> = roundtrip("abä").unwrap();
+        assert_eq!(s, "abä");
+        assert_eq!(
+            roundtrip::<AStr<2>>("abc"),
+            Err(AStrError::Length {
+                expected_len: 2,
+                actual_len: 3
+            })
+        );
+        assert!(AStr::<1>::try_from_utf8(&[0xff]).is_err());
+    }
+
+    #[test]
+    fn test_dyn_fixed_str() {
+        let strs: [&dyn DynFixedStr; 3] = [crate::astr!(""), crate::astr!("a"), crate::astr!("ä")];
+        let lens = strs.map(|s| s.as_bytes().len());
+        assert_eq!(lens, [0, 1, 2]);
+        assert_eq!(strs[2].as_str(), "ä");
+    }
+}
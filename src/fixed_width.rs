@@ -0,0 +1,168 @@
+//! Support for fixed-width text records, see [`FixedWidth`](crate::FixedWidth).
+
+use crate::{AStr, AStrError, Align, ArrayString};
+
+/// A field type whose width is known from the type itself.
+///
+/// Fields of these types need no `width` attribute in `#[derive(FixedWidth)]`, their content
+/// is used as is, without any padding.
+pub trait FixedField: Sized {
+    /// Width of the field in bytes.
+    const WIDTH: usize;
+
+    /// Create the field from its part of the record, `field` is exactly `WIDTH` bytes long.
+    fn from_field(field: &str) -> Result<Self, AStrError>;
+
+    /// Get the field content, this must be exactly `WIDTH` bytes long.
+    fn as_field(&self) -> &str;
+}
+
+impl<const LEN: usize> FixedField for AStr<LEN> {
+    const WIDTH: usize = LEN;
+
+    fn from_field(field: &str) -> Result<Self, AStrError> {
+        AStr::try_from(field)
+    }
+
+    fn as_field(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Error from parsing or writing a fixed-width record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixedWidthError {
+    /// The input is not a valid record.
+    Record(AStrError),
+    /// A field could not be cut out of the record or converted from it.
+    Field {
+        field: &'static str,
+        offset: usize,
+        error: AStrError,
+    },
+    /// The `FromStr` impl of a field rejected its content.
+    Parse { field: &'static str, offset: usize },
+    /// The formatted field does not fit into its width.
+    Overflow { field: &'static str, width: usize },
+}
+
+impl core::fmt::Display for FixedWidthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Record(err) => write!(f, "invalid record: {}", err),
+            Self::Field {
+                field,
+                offset,
+                error,
+            } => write!(
+                f,
+                "invalid field `{}` at offset {}: {}",
+                field, offset, error
+            ),
+            Self::Parse { field, offset } => {
+                write!(f, "could not parse field `{}` at offset {}", field, offset)
+            }
+            Self::Overflow { field, width } => {
+                write!(f, "field `{}` does not fit into {} bytes", field, width)
+            }
+        }
+    }
+}
+
+impl core::error::Error for FixedWidthError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Record(ref err) | Self::Field { error: ref err, .. } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+fn field_str<'a>(
+    record: &'a str,
+    offset: usize,
+    width: usize,
+    field: &'static str,
+) -> Result<&'a str, FixedWidthError> {
+    let bytes = &record.as_bytes()[offset..offset + width];
+    core::str::from_utf8(bytes).map_err(|err| FixedWidthError::Field {
+        field,
+        offset,
+        error: err.into(),
+    })
+}
+
+#[doc(hidden)]
+pub fn parse_fixed<T: FixedField>(
+    record: &str,
+    offset: usize,
+    field: &'static str,
+) -> Result<T, FixedWidthError> {
+    let s = field_str(record, offset, T::WIDTH, field)?;
+    T::from_field(s).map_err(|error| FixedWidthError::Field {
+        field,
+        offset,
+        error,
+    })
+}
+
+#[doc(hidden)]
+pub fn parse_padded<T: core::str::FromStr>(
+    record: &str,
+    offset: usize,
+    width: usize,
+    pad: char,
+    align: Align,
+    field: &'static str,
+) -> Result<T, FixedWidthError> {
+    let s = field_str(record, offset, width, field)?;
+    let trimmed = match align {
+        Align::Left => s.trim_end_matches(pad),
+        Align::Right => s.trim_start_matches(pad),
+        Align::Center => s.trim_matches(pad),
+    };
+    // a zero padded number that is all padding is still a zero, types that accept an empty
+    // str like `String` get it unchanged
+    match trimmed.parse() {
+        Err(_) if trimmed.is_empty() && pad.is_ascii_digit() && !s.is_empty() => {
+            s[s.len() - 1..].parse()
+        }
+        res => res,
+    }
+    .map_err(|_| FixedWidthError::Parse { field, offset })
+}
+
+fn write_str(record: &mut [u8], offset: usize, width: usize, s: &str) {
+    record[offset..offset + width].copy_from_slice(s.as_bytes());
+}
+
+#[doc(hidden)]
+pub fn write_fixed<T: FixedField>(record: &mut [u8], offset: usize, value: &T) {
+    write_str(record, offset, T::WIDTH, value.as_field())
+}
+
+#[doc(hidden)]
+pub fn write_padded<T: core::fmt::Display, const WIDTH: usize>(
+    record: &mut [u8],
+    offset: usize,
+    value: &T,
+    pad: char,
+    align: Align,
+    field: &'static str,
+) -> Result<(), FixedWidthError> {
+    use core::fmt::Write;
+
+    let mut buf = ArrayString::<WIDTH>::new();
+    write!(buf, "{}", value).map_err(|_| FixedWidthError::Overflow {
+        field,
+        width: WIDTH,
+    })?;
+    let s = AStr::<WIDTH>::from_str_padded(&buf, pad, align).map_err(|_| {
+        FixedWidthError::Overflow {
+            field,
+            width: WIDTH,
+        }
+    })?;
+    write_str(record, offset, WIDTH, &s);
+    Ok(())
+}
@@ -0,0 +1,436 @@
+//! Reading and writing [AStr]s with [std::io].
+
+use crate::{AStr, AStrError};
+use std::io::{self, BufRead, Read, Write};
+
+#[cfg(feature = "futures-io")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "futures-io")))]
+pub mod futures;
+#[cfg(feature = "tokio")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "tokio")))]
+pub mod tokio;
+
+impl<const N: usize> AStr<N> {
+    /// Read exactly `N` bytes and check that they are valid UTF-8.
+    ///
+    /// ```rust
+    /// use astr::AStr;
+    ///
+    /// let mut input = b"RIFF\x24\x08".as_slice();
+    /// assert_eq!(AStr::<4>::read_from(&mut input).unwrap(), "RIFF");
+    /// assert_eq!(input, [0x24, 0x08]);
+    /// ```
+    ///
+    /// # Errors
+    /// Fails with [`io::ErrorKind::UnexpectedEof`] if the reader ends early and with
+    /// [`io::ErrorKind::InvalidData`] wrapping an [AStrError] if the bytes are not valid UTF-8.
+    pub fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let mut buf = [0; N];
+        reader.read_exact(&mut buf)?;
+        checked(buf)
+    }
+
+    /// Write all `N` bytes.
+    pub fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.as_bytes())
+    }
+
+    /// Get a reader over the bytes.
+    ///
+    /// ```rust
+    /// use astr::astr;
+    /// use std::io::Read;
+    ///
+    /// let mut out = String::new();
+    /// astr!("Käse").reader().read_to_string(&mut out).unwrap();
+    /// assert_eq!(out, "Käse");
+    /// ```
+    pub fn reader(&self) -> io::Cursor<&Self> {
+        io::Cursor::new(self)
+    }
+}
+
+/// Check the bytes that were read, shared with the async readers.
+fn checked<const N: usize>(buf: [u8; N]) -> io::Result<AStr<N>> {
+    match core::str::from_utf8(&buf) {
+        // SAFETY: checked above
+        Ok(_) => Ok(unsafe { AStr::from_utf8_array_unchecked(buf) }),
+        Err(err) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            AStrError::from(err),
+        )),
+    }
+}
+
+/// Read [AStr]s from any [Read], like `byteorder` does for numbers.
+///
+/// ```rust
+/// use astr::io::ReadAStrExt;
+///
+/// let mut input = "GIF89a".as_bytes();
+/// let magic = input.read_astr::<3>().unwrap();
+/// let version = input.read_astr::<3>().unwrap();
+/// assert_eq!(magic, "GIF");
+/// assert_eq!(version, "89a");
+/// ```
+pub trait ReadAStrExt: Read {
+    /// Read exactly `N` bytes of UTF-8, see [`AStr::read_from`].
+    fn read_astr<const N: usize>(&mut self) -> io::Result<AStr<N>> {
+        AStr::read_from(self)
+    }
+}
+
+impl<R: Read + ?Sized> ReadAStrExt for R {}
+
+/// Write [AStr]s to any [Write].
+pub trait WriteAStrExt: Write {
+    /// Write all bytes of `s`, see [`AStr::write_to`].
+    fn write_astr<const N: usize>(&mut self, s: &AStr<N>) -> io::Result<()> {
+        s.write_to(self)
+    }
+}
+
+impl<W: Write + ?Sized> WriteAStrExt for W {}
+
+/// Reads newline separated records of exactly `N` bytes.
+///
+/// Lines may end in `\n` or `\r\n`, the last line may have no line ending. A single buffer is
+/// reused for all lines, at most `N + 2` bytes of a line are buffered. Longer lines are skipped
+/// and reported as a [`Length`](AStrError::Length) error.
+///
+/// ```rust
+/// use astr::io::FixedWidthReader;
+///
+/// let input = "0042Berlin\r\n0815Hamburg\n".as_bytes();
+/// let mut reader = FixedWidthReader::<_, 10>::new(input);
+///
+/// assert_eq!(reader.next().unwrap().unwrap(), "0042Berlin");
+///
+/// let err = reader.next().unwrap().unwrap_err();
+/// assert_eq!(err.line(), 2);
+/// assert!(reader.next().is_none());
+/// ```
+#[derive(Debug)]
+pub struct FixedWidthReader<R, const N: usize> {
+    reader: R,
+    buf: Vec<u8>,
+    line: usize,
+}
+
+impl<R: BufRead, const N: usize> FixedWidthReader<R, N> {
+    /// Create a new reader
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::with_capacity(N + 2),
+            line: 0,
+        }
+    }
+
+    /// Read the next record, `None` at the end of the input.
+    pub fn read_record(&mut self) -> Option<Result<AStr<N>, ReadError>> {
+        self.buf.clear();
+        let line = self.line + 1;
+        let limit = N + 2;
+        match (&mut self.reader)
+            .take(limit as u64)
+            .read_until(b'\n', &mut self.buf)
+        {
+            Ok(0) => return None,
+            Ok(_) => self.line = line,
+            Err(error) => return Some(Err(ReadError::Io { line, error })),
+        }
+
+        if self.buf.len() == limit && !self.buf.ends_with(b"\n") {
+            return Some(match self.skip_line() {
+                Ok(actual_len) => Err(ReadError::Record {
+                    line,
+                    error: AStrError::Length {
+                        expected_len: N,
+                        actual_len,
+                    },
+                }),
+                Err(error) => Err(ReadError::Io { line, error }),
+            });
+        }
+
+        let mut record = self.buf.as_slice();
+        if let Some(rest) = record.strip_suffix(b"\n") {
+            record = rest.strip_suffix(b"\r").unwrap_or(rest);
+        }
+        Some(
+            AStr::try_from_utf8(record)
+                .copied()
+                .map_err(|error| ReadError::Record { line, error }),
+        )
+    }
+
+    /// Consume the rest of a line that is too long for a record, without buffering it.
+    ///
+    /// Returns the length of the whole line without its line ending.
+    fn skip_line(&mut self) -> io::Result<usize> {
+        let mut len = self.buf.len();
+        let mut last = self.buf.last().copied();
+        loop {
+            let available = match self.reader.fill_buf() {
+                Ok(available) => available,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            match available.iter().position(|&b| b == b'\n') {
+                Some(end) => {
+                    if end > 0 {
+                        last = Some(available[end - 1]);
+                    }
+                    self.reader.consume(end + 1);
+                    return Ok(len + end - usize::from(last == Some(b'\r')));
+                }
+                None if available.is_empty() => return Ok(len),
+                None => {
+                    let n = available.len();
+                    last = available.last().copied();
+                    self.reader.consume(n);
+                    len += n;
+                }
+            }
+        }
+    }
+
+    /// Number of lines read so far.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead, const N: usize> Iterator for FixedWidthReader<R, N> {
+    type Item = Result<AStr<N>, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record()
+    }
+}
+
+/// Error from reading a fixed-width record, with the 1-based line it occurred on.
+#[derive(Debug)]
+pub enum ReadError {
+    /// The underlying reader failed.
+    Io { line: usize, error: io::Error },
+    /// The line is not a valid record.
+    Record { line: usize, error: AStrError },
+}
+
+impl ReadError {
+    /// The 1-based line of the failed record.
+    pub fn line(&self) -> usize {
+        match self {
+            Self::Io { line, .. } | Self::Record { line, .. } => *line,
+        }
+    }
+}
+
+impl core::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io { line, error } => write!(f, "line {}: {}", line, error),
+            Self::Record { line, error } => write!(f, "line {}: invalid record: {}", line, error),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { ref error, .. } => Some(error),
+            Self::Record { ref error, .. } => Some(error),
+        }
+    }
+}
+
+impl From<ReadError> for io::Error {
+    fn from(err: ReadError) -> Self {
+        match err {
+            ReadError::Io { error, .. } => error,
+            ReadError::Record { .. } => io::Error::new(io::ErrorKind::InvalidData, err),
+        }
+    }
+}
+
+/// Writes [AStr] records, each followed by a terminator.
+///
+/// ```rust
+/// use astr::{astr, io::FixedWidthWriter};
+///
+/// let mut writer = FixedWidthWriter::new(Vec::new()).with_terminator("\r\n");
+/// writer.write_record(astr!("0042Berlin")).unwrap();
+/// assert_eq!(writer.into_inner(), b"0042Berlin\r\n");
+/// ```
+#[derive(Debug)]
+pub struct FixedWidthWriter<W> {
+    writer: W,
+    terminator: &'static str,
+}
+
+impl<W: Write> FixedWidthWriter<W> {
+    /// Create a new writer that ends records with `\n`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            terminator: "\n",
+        }
+    }
+
+    /// Set the str written after each record, use `""` for none.
+    pub fn with_terminator(mut self, terminator: &'static str) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Write a record followed by the terminator.
+    pub fn write_record<const N: usize>(&mut self, record: &AStr<N>) -> io::Result<()> {
+        self.writer.write_all(record.as_bytes())?;
+        self.writer.write_all(self.terminator.as_bytes())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FixedWidthReader, FixedWidthWriter, ReadAStrExt, ReadError, WriteAStrExt};
+    use crate::{AStr, AStrError};
+    use std::io::{ErrorKind, Read};
+
+    #[test]
+    fn test_read_write_astr() {
+        let mut out = Vec::new();
+        out.write_astr(crate::astr!("äb")).unwrap();
+        crate::astr!("cd").write_to(&mut out).unwrap();
+        let mut input = out.as_slice();
+        assert_eq!(input.read_astr::<3>().unwrap(), "äb");
+        assert_eq!(input.read_astr::<1>().unwrap(), "c");
+        let err = input.read_astr::<2>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_read_astr_invalid() {
+        let mut input = "äb".as_bytes();
+        let err = input.read_astr::<1>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let inner = err.into_inner().unwrap().downcast::<AStrError>().unwrap();
+        assert!(matches!(*inner, AStrError::Utf8 { valid_up_to: 0, .. }));
+    }
+
+    #[test]
+    fn test_reader() {
+        let s = crate::astr!("abcd");
+        let mut reader = s.reader();
+        let mut buf = [0; 3];
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf, b"abc");
+        assert_eq!(reader.read(&mut buf).unwrap(), 1);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_fixed_width_reader() {
+        let input = "abc\r\ndef\nghi".as_bytes();
+        let records = FixedWidthReader::<_, 3>::new(input)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(records, ["abc", "def", "ghi"]);
+    }
+
+    #[test]
+    fn test_reader_errors() {
+        let input = b"abc\nab\n\xff\xfe\xfd\nxyz\n".as_slice();
+        let mut reader = FixedWidthReader::<_, 3>::new(input);
+        assert!(reader.next().unwrap().is_ok());
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ReadError::Record { line: 2, .. }))
+        ));
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ReadError::Record { line: 3, .. }))
+        ));
+        assert_eq!(reader.next().unwrap().unwrap(), "xyz");
+        assert!(reader.next().is_none());
+        assert_eq!(reader.line(), 4);
+    }
+
+    #[test]
+    fn test_reader_long_line() {
+        let long = "x".repeat(10_000);
+        let input = format!("abc\n{long}\r\ndef\n{long}");
+        // a small buffer makes the skipped line span several `fill_buf` calls
+        let input = std::io::BufReader::with_capacity(16, input.as_bytes());
+        let mut reader = FixedWidthReader::<_, 3>::new(input);
+        let too_long = AStrError::Length {
+            expected_len: 3,
+            actual_len: 10_000,
+        };
+        assert_eq!(reader.next().unwrap().unwrap(), "abc");
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ReadError::Record { line: 2, error })) if error == too_long
+        ));
+        assert_eq!(reader.next().unwrap().unwrap(), "def");
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ReadError::Record { line: 4, error })) if error == too_long
+        ));
+        assert!(reader.next().is_none());
+        assert!(reader.buf.capacity() < 16);
+
+        let mut reader = FixedWidthReader::<_, 3>::new("abcd\r\n".as_bytes());
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ReadError::Record {
+                error: AStrError::Length { actual_len: 4, .. },
+                ..
+            }))
+        ));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_writer_roundtrip() {
+        let mut writer = FixedWidthWriter::new(Vec::new()).with_terminator("\r\n");
+        writer.write_record(crate::astr!("abc")).unwrap();
+        writer.write_record(crate::astr!("dä")).unwrap();
+        let out = writer.into_inner();
+        assert_eq!(out, "abc\r\ndä\r\n".as_bytes());
+
+        let read = FixedWidthReader::<_, 3>::new(out.as_slice())
+            .collect::<Result<Vec<AStr<3>>, _>>()
+            .unwrap();
+        assert_eq!(read, ["abc", "dä"]);
+    }
+}
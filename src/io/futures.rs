@@ -0,0 +1,232 @@
+//! Reading and writing [AStr]s with the [futures_io] traits.
+
+use crate::AStr;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures_io::{AsyncRead, AsyncWrite};
+use std::io;
+
+/// Read [AStr]s from any [AsyncRead], the async version of
+/// [ReadAStrExt](crate::io::ReadAStrExt).
+///
+/// ```rust
+/// use astr::io::futures::AsyncReadAStrExt;
+/// use futures::io::Cursor;
+///
+/// # futures::executor::block_on(async {
+/// let mut input = Cursor::new("GIF89a");
+/// assert_eq!(input.read_astr::<3>().await.unwrap(), "GIF");
+/// # })
+/// ```
+pub trait AsyncReadAStrExt: AsyncRead + Unpin {
+    /// Read exactly `N` bytes of UTF-8, with the same errors as [`AStr::read_from`].
+    fn read_astr<const N: usize>(&mut self) -> ReadAStr<'_, Self, N> {
+        ReadAStr {
+            reader: self,
+            buf: [0; N],
+            filled: 0,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> AsyncReadAStrExt for R {}
+
+/// Write [AStr]s to any [AsyncWrite].
+pub trait AsyncWriteAStrExt: AsyncWrite + Unpin {
+    /// Write all bytes of `s`, see [`AStr::write_to`].
+    fn write_astr<'a, const N: usize>(&'a mut self, s: &'a AStr<N>) -> WriteAStr<'a, Self, N> {
+        WriteAStr {
+            writer: self,
+            s,
+            written: 0,
+        }
+    }
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> AsyncWriteAStrExt for W {}
+
+/// Future returned by [`AsyncReadAStrExt::read_astr`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadAStr<'a, R: ?Sized, const N: usize> {
+    reader: &'a mut R,
+    buf: [u8; N],
+    filled: usize,
+}
+
+impl<R: AsyncRead + Unpin + ?Sized, const N: usize> Future for ReadAStr<'_, R, N> {
+    type Output = io::Result<AStr<N>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        while this.filled < N {
+            let buf = &mut this.buf[this.filled..];
+            match ready!(Pin::new(&mut *this.reader).poll_read(cx, buf))? {
+                0 => return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into())),
+                n => this.filled += n,
+            }
+        }
+        Poll::Ready(super::checked(this.buf))
+    }
+}
+
+/// Future returned by [`AsyncWriteAStrExt::write_astr`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WriteAStr<'a, W: ?Sized, const N: usize> {
+    writer: &'a mut W,
+    s: &'a AStr<N>,
+    written: usize,
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized, const N: usize> Future for WriteAStr<'_, W, N> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        while this.written < N {
+            let rest = &this.s.as_bytes()[this.written..];
+            match ready!(Pin::new(&mut *this.writer).poll_write(cx, rest))? {
+                0 => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                n => this.written += n,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsyncReadAStrExt, AsyncWriteAStrExt};
+    use crate::{astr, AStrError};
+    use core::{
+        pin::Pin,
+        task::{ready, Context, Poll},
+    };
+    use futures::{
+        executor::block_on,
+        io::{AsyncRead, AsyncWrite, Cursor},
+    };
+    use std::io::{self, ErrorKind};
+
+    /// Moves at most one byte per poll and returns `Pending` before every byte.
+    struct Trickle<T> {
+        inner: T,
+        ready: bool,
+    }
+
+    impl<T> Trickle<T> {
+        fn new(inner: T) -> Self {
+            Trickle {
+                inner,
+                ready: false,
+            }
+        }
+
+        fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+            self.ready = !self.ready;
+            if self.ready {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            } else {
+                Poll::Ready(())
+            }
+        }
+    }
+
+    impl<T: AsyncRead + Unpin> AsyncRead for Trickle<T> {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = self.get_mut();
+            ready!(this.poll_ready(cx));
+            let len = buf.len().min(1);
+            Pin::new(&mut this.inner).poll_read(cx, &mut buf[..len])
+        }
+    }
+
+    impl<T: AsyncWrite + Unpin> AsyncWrite for Trickle<T> {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = self.get_mut();
+            ready!(this.poll_ready(cx));
+            let len = buf.len().min(1);
+            Pin::new(&mut this.inner).poll_write(cx, &buf[..len])
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.get_mut().inner).poll_close(cx)
+        }
+    }
+
+    #[test]
+    fn test_trickle() {
+        // every byte takes a `Pending` and a partial read or write
+        block_on(async {
+            let mut writer = Trickle::new(Cursor::new(Vec::new()));
+            writer.write_astr(astr!("HDRäö")).await.unwrap();
+            writer.write_astr(astr!("x")).await.unwrap();
+            assert_eq!(writer.inner.get_ref(), "HDRäöx".as_bytes());
+
+            let mut reader = Trickle::new(Cursor::new(writer.inner.into_inner()));
+            assert_eq!(reader.read_astr::<7>().await.unwrap(), "HDRäö");
+            assert_eq!(reader.read_astr::<1>().await.unwrap(), "x");
+
+            let err = reader.read_astr::<1>().await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        });
+    }
+
+    #[test]
+    fn test_cursor() {
+        block_on(async {
+            let mut cursor = Cursor::new(Vec::new());
+            cursor.write_astr(astr!("HDRäö")).await.unwrap();
+            cursor.write_astr(astr!("x")).await.unwrap();
+            cursor.set_position(0);
+            assert_eq!(cursor.read_astr::<7>().await.unwrap(), "HDRäö");
+            assert_eq!(cursor.read_astr::<1>().await.unwrap(), "x");
+
+            let err = cursor.read_astr::<1>().await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        });
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        block_on(async {
+            let mut input = Cursor::new("äb");
+            let err = input.read_astr::<1>().await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+            let inner = err.into_inner().unwrap().downcast::<AStrError>().unwrap();
+            assert!(matches!(*inner, AStrError::Utf8 { valid_up_to: 0, .. }));
+        });
+    }
+
+    #[test]
+    fn test_write_zero() {
+        block_on(async {
+            let mut out = [0; 2];
+            let mut cursor = Cursor::new(out.as_mut_slice());
+            let err = cursor.write_astr(astr!("abc")).await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::WriteZero);
+
+            let mut out = [0; 2];
+            let mut writer = Trickle::new(Cursor::new(out.as_mut_slice()));
+            let err = writer.write_astr(astr!("abc")).await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::WriteZero);
+        });
+    }
+}
@@ -0,0 +1,139 @@
+//! Reading and writing [AStr]s with the [tokio] io traits.
+
+use crate::AStr;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use std::io;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// Read [AStr]s from any [AsyncRead], the async version of
+/// [ReadAStrExt](crate::io::ReadAStrExt).
+///
+/// ```rust
+/// use astr::io::tokio::AsyncReadAStrExt;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut input = "GIF89a".as_bytes();
+/// assert_eq!(input.read_astr::<3>().await.unwrap(), "GIF");
+/// # })
+/// ```
+pub trait AsyncReadAStrExt: AsyncRead + Unpin {
+    /// Read exactly `N` bytes of UTF-8, with the same errors as [`AStr::read_from`].
+    fn read_astr<const N: usize>(&mut self) -> ReadAStr<'_, Self, N> {
+        ReadAStr {
+            reader: self,
+            buf: [0; N],
+            filled: 0,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> AsyncReadAStrExt for R {}
+
+/// Write [AStr]s to any [AsyncWrite].
+pub trait AsyncWriteAStrExt: AsyncWrite + Unpin {
+    /// Write all bytes of `s`, see [`AStr::write_to`].
+    fn write_astr<'a, const N: usize>(&'a mut self, s: &'a AStr<N>) -> WriteAStr<'a, Self, N> {
+        WriteAStr {
+            writer: self,
+            s,
+            written: 0,
+        }
+    }
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> AsyncWriteAStrExt for W {}
+
+/// Future returned by [`AsyncReadAStrExt::read_astr`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ReadAStr<'a, R: ?Sized, const N: usize> {
+    reader: &'a mut R,
+    buf: [u8; N],
+    filled: usize,
+}
+
+impl<R: AsyncRead + Unpin + ?Sized, const N: usize> Future for ReadAStr<'_, R, N> {
+    type Output = io::Result<AStr<N>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        while this.filled < N {
+            let mut buf = ReadBuf::new(&mut this.buf[this.filled..]);
+            ready!(Pin::new(&mut *this.reader).poll_read(cx, &mut buf))?;
+            match buf.filled().len() {
+                0 => return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into())),
+                n => this.filled += n,
+            }
+        }
+        Poll::Ready(super::checked(this.buf))
+    }
+}
+
+/// Future returned by [`AsyncWriteAStrExt::write_astr`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WriteAStr<'a, W: ?Sized, const N: usize> {
+    writer: &'a mut W,
+    s: &'a AStr<N>,
+    written: usize,
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized, const N: usize> Future for WriteAStr<'_, W, N> {
+    type Output = io::Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        while this.written < N {
+            let rest = &this.s.as_bytes()[this.written..];
+            match ready!(Pin::new(&mut *this.writer).poll_write(cx, rest))? {
+                0 => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                n => this.written += n,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsyncReadAStrExt, AsyncWriteAStrExt};
+    use crate::{astr, AStrError};
+    use std::io::ErrorKind;
+
+    #[tokio::test]
+    async fn test_duplex() {
+        // a tiny buffer splits the writes and reads into several polls
+        let (mut client, mut server) = tokio::io::duplex(2);
+        let writer = tokio::spawn(async move {
+            client.write_astr(astr!("HDRäö")).await.unwrap();
+            client.write_astr(astr!("x")).await.unwrap();
+        });
+        assert_eq!(server.read_astr::<7>().await.unwrap(), "HDRäö");
+        assert_eq!(server.read_astr::<1>().await.unwrap(), "x");
+        writer.await.unwrap();
+
+        let err = server.read_astr::<1>().await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_utf8() {
+        let mut input = "äb".as_bytes();
+        let err = input.read_astr::<1>().await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let inner = err.into_inner().unwrap().downcast::<AStrError>().unwrap();
+        assert!(matches!(*inner, AStrError::Utf8 { valid_up_to: 0, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_write_zero() {
+        let mut out = [0; 2];
+        let mut writer = std::io::Cursor::new(out.as_mut_slice());
+        let err = writer.write_astr(astr!("abc")).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+    }
+}
@@ -1,8 +1,16 @@
-#![cfg_attr(not(feature = "std"), no_std)]
+// the tests always use `std`, `cargo build --no-default-features` checks the `no_std` build
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::{array::TryFromSliceError, str::Utf8Error};
 mod valid_input {
+    /// # Safety
+    /// Implementors must only expose bytes that are valid UTF-8 through `as_bytes`.
     pub unsafe trait ValidInput {}
     unsafe impl ValidInput for &str {}
     unsafe impl<const LEN: usize> ValidInput for &crate::AStr<LEN> {}
@@ -15,6 +23,50 @@ mod valid_input {
 #[doc(hidden)]
 pub use valid_input::valid_input;
 
+mod piece;
+#[doc(hidden)]
+pub use piece::{Concat, Piece};
+
+mod const_str;
+mod pad;
+mod slice;
+mod transform;
+pub use pad::{Align, Formatted};
+
+pub mod fixed_width;
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+pub use astr_macros::format_astr_exact;
+#[cfg(feature = "derive")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "derive")))]
+pub use astr_macros::{AStrNewtype, FixedWidth};
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+pub mod io;
+
+mod array_string;
+pub use array_string::{ArrayString, CapacityError};
+
+mod builder;
+pub use builder::AStrBuilder;
+
+mod cursor;
+pub use cursor::AStrCursor;
+
+mod fixed_str;
+pub use fixed_str::{DynFixedStr, FixedStr};
+
+pub mod validate;
+pub use validate::Validated;
+
+mod ascii;
+pub use ascii::AsciiAStr;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
+
 /// # astr
 /// Build an AStr from a string literal.
 ///
@@ -42,6 +94,26 @@ pub use valid_input::valid_input;
 /// assert_eq!(s, "ääääääääää");
 /// ```
 ///
+/// ## Repeat a str
+/// The pattern can also be a `&str` or an [AStr], the count any const expression.
+/// ```rust
+/// use astr::{astr, AStr};
+///
+/// const COUNT: usize = 3;
+/// const S: AStr<6> = astr!("ab"; COUNT);
+/// assert_eq!(S, "ababab");
+///
+/// let s = astr!(astr!("äb"); COUNT - 1);
+/// assert_eq!(s, "äbäb");
+/// ```
+///
+/// The length is computed at compile time, so it can not go wrong at runtime.
+/// ```rust,compile_fail
+/// use astr::astr;
+///
+/// let s = astr!("ab"; usize::MAX);
+/// ```
+///
 #[macro_export]
 macro_rules! astr {
     ($input:expr) => {
@@ -52,12 +124,44 @@ macro_rules! astr {
             $crate::AStr::<LEN>::from_utf8_unchecked($input.as_bytes())
         }
     };
-    ($input:expr; $expr:literal) => {{
-        const CHAR: char = $input;
+    ($input:expr; $count:expr) => {{
+        const LEN: usize = $crate::Piece($input).len_utf8() * ($count);
 
-        const LEN: usize = $len * CHAR.len_utf8();
+        const RET: $crate::AStr<LEN> = $crate::Piece($input).repeat::<LEN>();
+        RET
+    }};
+}
 
-        const RET: $crate::AStr<LEN> = $crate::AStr::<LEN>::repeat(CHAR);
+/// # astr_concat
+/// Concatenate [AStr]s, string literals and chars into a new [AStr].
+///
+/// The length of the result is computed at compile time, so all inputs must be const
+/// expressions. The macro can be used in `const` items.
+///
+/// ```rust
+/// use astr::{astr, astr_concat, AStr};
+///
+/// const HELLO: AStr<5> = *astr!("hello");
+/// const S: AStr<12> = astr_concat!(HELLO, ' ', astr!("world"), "!");
+/// assert_eq!(S, "hello world!");
+/// ```
+///
+/// A wrong length for the result does not compile.
+/// ```rust,compile_fail
+/// use astr::{astr_concat, AStr};
+///
+/// const S: AStr<4> = astr_concat!("ab", 'c');
+/// ```
+#[macro_export]
+macro_rules! astr_concat {
+    ($($input:expr),* $(,)?) => {{
+        const LEN: usize = 0 $(+ $crate::Piece($input).len_utf8())*;
+
+        const RET: $crate::AStr<LEN> = {
+            let buf = $crate::Concat::<LEN>::EMPTY;
+            $(let buf = $crate::Piece($input).append_to(buf);)*
+            buf.finish()
+        };
         RET
     }};
 }
@@ -66,12 +170,12 @@ macro_rules! astr {
 ///
 /// Because an [AStr] has a fixed length, the format string must expand to exactly the expected
 /// number of bytes, or it would be impossible to build the AStr. As a result, this macro evaluates
-/// to a `Result<AStr, std::fmt::Error>` to carry the error, if any.
+/// to a `Result<AStr, AStrError>` to carry the error, if any.
 ///
 /// The `LEN` of the returned `AStr` has to be inferred from the expansion site, there is no way to
 /// set it explicitly in this macro's syntax.
 ///
-/// This macro uses the same syntax as [`format!`], but creates a `Result<AStr, std::fmt::Error>`
+/// This macro uses the same syntax as [`format!`], but creates a `Result<AStr, AStrError>`
 /// instead. See [`std::fmt`] for more information.
 ///
 /// ## Examples
@@ -85,10 +189,64 @@ macro_rules! astr {
 /// use uuid::Uuid;
 /// let uuid: AStr<36> = format_astr!("{}", Uuid::new_v4()).unwrap();
 /// ```
+///
+/// With the `macros` feature, `format_astr_exact!` checks the length at compile time and returns
+/// the [AStr] directly.
 #[macro_export]
 macro_rules! format_astr {
     ($($arg:tt)*) => {
-        $crate::AStr::try_from_fmt(format_args!($($arg)*))
+        $crate::AStr::try_from_args(format_args!($($arg)*))
+    }
+}
+
+/// Build an [AStr] from a format string, padding output that is too short and cutting output
+/// that is too long.
+///
+/// The first two arguments are the pad char and the [Align]ment of the output, the rest is the
+/// same as for [`format_astr!`]. Too long output is cut at the last char boundary that fits. The
+/// macro evaluates to a [Formatted], which tells whether the output was truncated.
+///
+/// ```
+/// use astr::{format_astr_padded, Align, Formatted};
+///
+/// let id: Formatted<6> = format_astr_padded!('0', Align::Right, "{}", 42);
+/// assert_eq!(id.value, "000042");
+/// assert!(!id.truncated);
+///
+/// let id: Formatted<6> = format_astr_padded!('0', Align::Right, "{}", 1234567);
+/// assert_eq!(id.value, "123456");
+/// assert!(id.truncated);
+/// ```
+///
+/// Bytes left over because the missing bytes are not a multiple of the pad char utf8 len are
+/// filled with `' '`.
+#[macro_export]
+macro_rules! format_astr_padded {
+    ($pad:expr, $align:expr, $($arg:tt)*) => {
+        $crate::AStr::from_args_padded($pad, $align, format_args!($($arg)*))
+    }
+}
+
+/// Build an [AStr] from a format string, cutting output that is too long.
+///
+/// Like [`format_astr_padded!`] with `' '` as pad char and [`Align::Left`]. Output is cut at
+/// the last char boundary that fits, so a few bytes at the end can still be padding.
+///
+/// ```
+/// use astr::{format_astr_truncated, Formatted};
+///
+/// let column: Formatted<8> = format_astr_truncated!("{}: {}", "Käsebrot", 3);
+/// assert_eq!(column.value, "Käsebro");
+/// assert!(column.truncated);
+///
+/// let column: Formatted<8> = format_astr_truncated!("{}", 3);
+/// assert_eq!(column.value, "3       ");
+/// assert!(!column.truncated);
+/// ```
+#[macro_export]
+macro_rules! format_astr_truncated {
+    ($($arg:tt)*) => {
+        $crate::AStr::from_args_padded(' ', $crate::Align::Left, format_args!($($arg)*))
     }
 }
 
@@ -114,52 +272,199 @@ macro_rules! format_astr {
 /// assert_eq!(s, "Hallo World");
 /// ```
 #[repr(transparent)]
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy)]
 pub struct AStr<const LEN: usize>([u8; LEN]);
 
-impl<const LEN: usize> std::hash::Hash for AStr<LEN> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+impl<const LEN: usize> core::hash::Hash for AStr<LEN> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.as_str().hash(state);
     }
 }
 
-#[derive(Debug, Clone)]
+/// Error when an [AStr] can not be created from the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum AStrError {
-    Utf8(Utf8Error),
-    Slice(TryFromSliceError),
+    /// The input does not have the expected number of bytes.
+    Length {
+        expected_len: usize,
+        actual_len: usize,
+    },
+    /// The input is not valid UTF-8, see [`Utf8Error`] for the meaning of the fields.
+    Utf8 {
+        valid_up_to: usize,
+        error_len: Option<usize>,
+    },
+    /// The byte index does not lie on a char boundary.
+    CharBoundary { index: usize },
+    /// A buffer of packed records ends in a partial record, its `actual_len` is not a multiple
+    /// of `record_len`.
+    PartialRecord {
+        record_len: usize,
+        actual_len: usize,
+    },
+    /// The `gap` bytes to pad are not a multiple of the pad char utf8 len `pad_len`.
+    Padding { gap: usize, pad_len: usize },
+    /// [`AStr::try_from_fmt`] or an [AStrBuilder] did not write exactly `expected_len` bytes.
+    Fmt {
+        expected_len: usize,
+        kind: FmtErrorKind,
+    },
+    /// A slice could not be converted into an array, the lengths are unknown.
+    ///
+    /// Only created by the `From<TryFromSliceError>` conversion.
+    Slice,
+    /// A [Validator](validate::Validator) or the validation hook of `#[derive(AStrNewtype)]`
+    /// rejected the value.
+    Invalid { reason: &'static str },
+}
+
+/// Why [`AStr::try_from_fmt`] or [`AStrBuilder::finish`] failed, see [`AStrError::Fmt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FmtErrorKind {
+    /// The formatted output ended after `written` bytes.
+    TooShort { written: usize },
+    /// The output did not fit, writing was stopped.
+    Overflow,
+    /// The [`Display`](core::fmt::Display) implementation returned an error.
+    Error,
+}
+
+impl AStrError {
+    const fn length(expected_len: usize, actual_len: usize) -> Self {
+        Self::Length {
+            expected_len,
+            actual_len,
+        }
+    }
+
+    const fn utf8(err: Utf8Error) -> Self {
+        Self::Utf8 {
+            valid_up_to: err.valid_up_to(),
+            error_len: err.error_len(),
+        }
+    }
 }
 
 impl From<Utf8Error> for AStrError {
     fn from(err: Utf8Error) -> Self {
-        Self::Utf8(err)
+        Self::utf8(err)
     }
 }
 
 impl From<TryFromSliceError> for AStrError {
-    fn from(err: TryFromSliceError) -> Self {
-        Self::Slice(err)
+    fn from(_: TryFromSliceError) -> Self {
+        Self::Slice
     }
 }
 
 impl core::fmt::Display for AStrError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            Self::Utf8(err) => err.fmt(f),
-            Self::Slice(err) => err.fmt(f),
+        match *self {
+            Self::Length {
+                expected_len,
+                actual_len,
+            } => write!(f, "expected {} bytes but got {}", expected_len, actual_len),
+            Self::Utf8 {
+                valid_up_to,
+                error_len: Some(error_len),
+            } => write!(
+                f,
+                "invalid utf-8 sequence of {} bytes from index {}",
+                error_len, valid_up_to
+            ),
+            Self::Utf8 {
+                valid_up_to,
+                error_len: None,
+            } => write!(
+                f,
+                "incomplete utf-8 byte sequence from index {}",
+                valid_up_to
+            ),
+            Self::CharBoundary { index } => {
+                write!(f, "byte index {} is not a char boundary", index)
+            }
+            Self::PartialRecord {
+                record_len,
+                actual_len,
+            } => write!(
+                f,
+                "{} bytes are not a multiple of the record length {}",
+                actual_len, record_len
+            ),
+            Self::Padding { gap, pad_len } => write!(
+                f,
+                "{} bytes can not be padded with a char of {} bytes",
+                gap, pad_len
+            ),
+            Self::Fmt {
+                expected_len,
+                kind: FmtErrorKind::TooShort { written },
+            } => write!(
+                f,
+                "formatted output is {} bytes, expected {}",
+                written, expected_len
+            ),
+            Self::Fmt {
+                expected_len,
+                kind: FmtErrorKind::Overflow,
+            } => write!(f, "formatted output exceeds {} bytes", expected_len),
+            Self::Fmt {
+                kind: FmtErrorKind::Error,
+                ..
+            } => f.write_str("formatter error"),
+            Self::Slice => f.write_str("could not convert slice to array"),
+            Self::Invalid { reason } => write!(f, "invalid value: {}", reason),
         }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for AStrError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match self {
-            Self::Utf8(ref err) => Some(err),
-            Self::Slice(ref err) => Some(err),
-        }
+impl core::error::Error for AStrError {}
+
+/// Convert a slice to an array reference, reporting both lengths on failure.
+const fn array_ref<const LEN: usize>(slice: &[u8]) -> Result<&[u8; LEN], AStrError> {
+    match slice.first_chunk() {
+        Some(arr) if slice.len() == LEN => Ok(arr),
+        _ => Err(AStrError::length(LEN, slice.len())),
+    }
+}
+
+/// Convert a slice to a mutable array reference, reporting both lengths on failure.
+const fn array_mut<const LEN: usize>(slice: &mut [u8]) -> Result<&mut [u8; LEN], AStrError> {
+    let len = slice.len();
+    match slice.first_chunk_mut() {
+        Some(arr) if len == LEN => Ok(arr),
+        _ => Err(AStrError::length(LEN, len)),
+    }
+}
+
+/// Panic with a message that is also readable when it happens at compile time.
+#[track_caller]
+const fn invalid_input(err: AStrError) -> ! {
+    match err {
+        AStrError::Length { .. } => panic!("the input length does not match the AStr length"),
+        AStrError::Utf8 { .. } => panic!("the input is not valid UTF-8"),
+        _ => panic!("invalid input for an AStr"),
     }
 }
 
+/// Returns `true` if `index` is the start of a char or the end of `bytes`.
+const fn is_char_boundary(bytes: &[u8], index: usize) -> bool {
+    index == bytes.len() || bytes[index] & 0xC0 != 0x80
+}
+
+/// Returns the last char boundary of the UTF-8 `bytes` that is not after `index`.
+const fn floor_char_boundary(bytes: &[u8], index: usize) -> usize {
+    if index >= bytes.len() {
+        return bytes.len();
+    }
+    let mut i = index;
+    while !is_char_boundary(bytes, i) {
+        i -= 1;
+    }
+    i
+}
+
 impl<const LEN: usize> AStr<LEN> {
     /// Create a new AStr from an array of bytes.
     /// # Safety
@@ -178,20 +483,51 @@ impl<const LEN: usize> AStr<LEN> {
     /// Create a new AStr from an array of bytes.
     /// # Safety
     /// The slice must be valid UTF-8.
-    pub unsafe fn from_utf8_array_unchecked_mut(arr: &mut [u8; LEN]) -> &mut Self {
+    pub const unsafe fn from_utf8_array_unchecked_mut(arr: &mut [u8; LEN]) -> &mut Self {
         core::mem::transmute(arr)
     }
 
     /// Create a new AStr from a slice of bytes.
-    pub fn try_from_utf8_array_ref(arr: &[u8; LEN]) -> Result<&Self, AStrError> {
-        core::str::from_utf8(arr)?;
-        Ok(unsafe { Self::from_utf8_array_unchecked_ref(arr) })
+    pub const fn try_from_utf8_array_ref(arr: &[u8; LEN]) -> Result<&Self, AStrError> {
+        match core::str::from_utf8(arr) {
+            Ok(_) => Ok(unsafe { Self::from_utf8_array_unchecked_ref(arr) }),
+            Err(err) => Err(AStrError::utf8(err)),
+        }
     }
 
     /// Create a new AStr from a slice of bytes.
-    pub fn try_from_utf8_array_mut(arr: &mut [u8; LEN]) -> Result<&mut Self, AStrError> {
-        core::str::from_utf8_mut(arr)?;
-        Ok(unsafe { Self::from_utf8_array_unchecked_mut(arr) })
+    pub const fn try_from_utf8_array_mut(arr: &mut [u8; LEN]) -> Result<&mut Self, AStrError> {
+        match core::str::from_utf8(arr) {
+            Ok(_) => Ok(unsafe { Self::from_utf8_array_unchecked_mut(arr) }),
+            Err(err) => Err(AStrError::utf8(err)),
+        }
+    }
+
+    /// Create a new AStr from an array of bytes, for use in `const` items.
+    ///
+    /// ```rust
+    /// use astr::AStr;
+    ///
+    /// const S: AStr<4> = AStr::from_bytes_const(b"abcd");
+    /// assert_eq!(S, "abcd");
+    /// ```
+    ///
+    /// Invalid UTF-8 fails to compile.
+    ///
+    /// ```rust,compile_fail
+    /// use astr::AStr;
+    ///
+    /// const S: AStr<2> = AStr::from_bytes_const(&[0xC3, 0x28]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the bytes are not valid UTF-8.
+    #[track_caller]
+    pub const fn from_bytes_const(bytes: &[u8; LEN]) -> Self {
+        match Self::try_from_utf8_array_ref(bytes) {
+            Ok(s) => *s,
+            Err(err) => invalid_input(err),
+        }
     }
 
     /// Create a new AStr from a slice of bytes.
@@ -204,27 +540,41 @@ impl<const LEN: usize> AStr<LEN> {
     }
 
     /// Create a new AStr from a slice of bytes.
-    pub fn try_from_utf8(slice: &[u8]) -> Result<&Self, AStrError> {
-        Self::try_from_utf8_array_ref(slice.try_into()?)
+    pub const fn try_from_utf8(slice: &[u8]) -> Result<&Self, AStrError> {
+        match array_ref(slice) {
+            Ok(arr) => Self::try_from_utf8_array_ref(arr),
+            Err(err) => Err(err),
+        }
     }
 
     /// Create a new AStr from a slice of bytes.
     /// # Panics
     /// Panics if the slice is not valid UTF-8 or the wrong length.
-    pub fn from_utf8(slice: &[u8]) -> &Self {
-        Self::try_from_utf8(slice).unwrap()
+    #[track_caller]
+    pub const fn from_utf8(slice: &[u8]) -> &Self {
+        match Self::try_from_utf8(slice) {
+            Ok(s) => s,
+            Err(err) => invalid_input(err),
+        }
     }
 
     /// Create a new AStr from a slice of bytes.
-    pub fn try_from_utf8_mut(slice: &mut [u8]) -> Result<&mut Self, AStrError> {
-        Self::try_from_utf8_array_mut(slice.try_into()?)
+    pub const fn try_from_utf8_mut(slice: &mut [u8]) -> Result<&mut Self, AStrError> {
+        match array_mut(slice) {
+            Ok(arr) => Self::try_from_utf8_array_mut(arr),
+            Err(err) => Err(err),
+        }
     }
 
     /// Create a new AStr from a slice of bytes.
     /// # Panics
     /// Panics if the slice is not valid UTF-8 or the wrong length.
-    pub fn from_utf8_mut(slice: &mut [u8]) -> &mut Self {
-        Self::try_from_utf8_mut(slice).unwrap()
+    #[track_caller]
+    pub const fn from_utf8_mut(slice: &mut [u8]) -> &mut Self {
+        match Self::try_from_utf8_mut(slice) {
+            Ok(s) => s,
+            Err(err) => invalid_input(err),
+        }
     }
 
     /// Create a new AStr from a slice of bytes.
@@ -235,31 +585,66 @@ impl<const LEN: usize> AStr<LEN> {
     }
 
     /// Create a new AStr from a str
-    pub fn try_from_str_ref(str: &str) -> Result<&Self, AStrError> {
-        let arr = str.as_bytes().try_into()?;
-        Ok(unsafe { Self::from_utf8_array_unchecked_ref(arr) })
+    pub const fn try_from_str_ref(str: &str) -> Result<&Self, AStrError> {
+        match array_ref(str.as_bytes()) {
+            Ok(arr) => Ok(unsafe { Self::from_utf8_array_unchecked_ref(arr) }),
+            Err(err) => Err(err),
+        }
     }
 
     /// Create a new AStr from a str
     /// # Panics
     /// Panics if the string is not th right length.
-    pub fn from_str_ref(str: &str) -> &Self {
-        Self::try_from_str_ref(str).unwrap()
+    #[track_caller]
+    pub const fn from_str_ref(str: &str) -> &Self {
+        match Self::try_from_str_ref(str) {
+            Ok(s) => s,
+            Err(err) => invalid_input(err),
+        }
+    }
+
+    /// Create a new AStr from a str, for use in `const` items.
+    ///
+    /// ```rust
+    /// use astr::AStr;
+    ///
+    /// const NAME: &str = "astr";
+    /// const S: AStr<4> = AStr::from_str_const(NAME);
+    /// assert_eq!(S, "astr");
+    /// ```
+    ///
+    /// A str of the wrong length fails to compile.
+    ///
+    /// ```rust,compile_fail
+    /// use astr::AStr;
+    ///
+    /// const S: AStr<3> = AStr::from_str_const("astr");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the string is not the right length.
+    #[track_caller]
+    pub const fn from_str_const(str: &str) -> Self {
+        *Self::from_str_ref(str)
     }
 
     /// Create a new AStr from a str
-    pub fn try_from_str_mut(str: &mut str) -> Result<&mut Self, AStrError> {
-        Ok(unsafe {
-            let arr = str.as_bytes_mut().try_into()?;
-            Self::from_utf8_array_unchecked_mut(arr)
-        })
+    pub const fn try_from_str_mut(str: &mut str) -> Result<&mut Self, AStrError> {
+        match array_mut(unsafe { str.as_bytes_mut() }) {
+            Ok(arr) => Ok(unsafe { Self::from_utf8_array_unchecked_mut(arr) }),
+            Err(err) => Err(err),
+        }
     }
 
     /// Create a new AStr from a str
     /// # Panics
     /// Panics if the string is not th right length.
-    pub fn from_str_mut(str: &mut str) -> &mut Self {
-        Self::try_from_str_mut(str).unwrap()
+    #[track_caller]
+    pub const fn from_str_mut(str: &mut str) -> &mut Self {
+        match Self::try_from_str_mut(str) {
+            Ok(s) => s,
+            Err(err) => invalid_input(err),
+        }
     }
 
     pub const fn as_ptr(&self) -> *const u8 {
@@ -335,6 +720,35 @@ impl<const LEN: usize> AStr<LEN> {
         unsafe { Self::from_utf8_array_unchecked(bytes) }
     }
 
+    /// repeat the str as often as it fits in the LEN str
+    ///
+    /// See also [`astr!`] which infers LEN from the repeat count.
+    ///
+    /// # Panics
+    /// Panics if the str length is not a divisor of LEN
+    pub const fn repeat_str(s: &str) -> Self {
+        let pattern = s.as_bytes();
+
+        assert!(
+            if pattern.is_empty() {
+                LEN == 0
+            } else {
+                LEN % pattern.len() == 0
+            },
+            "LEN is not a multiple of the str length"
+        );
+
+        let mut bytes = [0; LEN];
+        let mut i = 0;
+        while i < LEN {
+            bytes[i] = pattern[i % pattern.len()];
+            i += 1
+        }
+
+        // SAFETY: a whole number of copies of a valid str is valid UTF-8
+        unsafe { Self::from_utf8_array_unchecked(bytes) }
+    }
+
     /// Returns the length of self.
     ///
     /// This length is in bytes, not [char]s or graphemes. In other words, it might not be what a human considers the length of the string.
@@ -342,8 +756,15 @@ impl<const LEN: usize> AStr<LEN> {
         LEN
     }
 
+    /// Returns `true` if self has a length of zero bytes.
+    pub const fn is_empty(&self) -> bool {
+        LEN == 0
+    }
+
     /// Concatenate two [AStr]s.
     ///
+    /// See also [`astr_concat!`] which checks the length at compile time.
+    ///
     /// # Panics
     /// Panics if RET_LEN is not LEN + B_LEN
     pub fn concat<const B_LEN: usize, const RET_LEN: usize>(
@@ -359,6 +780,22 @@ impl<const LEN: usize> AStr<LEN> {
         unsafe { self.concat_unchecked(other) }
     }
 
+    /// Concatenate two [AStr]s, the length of the result is inferred.
+    ///
+    /// Requires the `nightly` feature and a nightly compiler.
+    #[cfg(feature = "nightly")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "nightly")))]
+    pub const fn concat_exact<const B_LEN: usize>(
+        &self,
+        other: &AStr<B_LEN>,
+    ) -> AStr<{ LEN + B_LEN }>
+    where
+        [(); LEN + B_LEN]:,
+    {
+        // SAFETY: the result length is exactly LEN + B_LEN
+        unsafe { self.concat_unchecked(other) }
+    }
+
     /// Concatenate two [AStr]s.
     /// # Safety
     /// RET_LEN must be LEN + B_LEN
@@ -384,67 +821,151 @@ impl<const LEN: usize> AStr<LEN> {
         AStr::<RET_LEN>::from_utf8_array_unchecked(ret_buf)
     }
 
-    /// Build an [AStr] from a an implementation of [`Display`][core::fmt::Display]
+    /// Split into two [AStr]s at byte index `MID`.
     ///
-    /// See also [`format_astr!`] for a more convenient syntax.
+    /// Fails to compile if `MID + REST` is not `LEN`. This shadows `str::split_at`, use
+    /// `as_str().split_at(n)` to split at a runtime index.
     ///
-    /// # Error
+    /// ```rust
+    /// use astr::{astr, AStr};
     ///
-    /// Because an [AStr] has a fixed length, `display` must write exactly the expected number of
-    /// bytes to its `Formatter`, or this function will return an `Err`.
-    #[doc(alias = "format", alias = "display")]
-    pub fn try_from_fmt(display: impl core::fmt::Display) -> Result<Self, core::fmt::Error> {
-        use core::fmt::Write;
-        let mut builder = FmtBuilder::new();
-        write!(builder, "{}", display)?;
-        builder.finalize()
+    /// const RECORD: AStr<10> = *astr!("0042Berlin");
+    /// const PARTS: (AStr<4>, AStr<6>) = RECORD.split_at();
+    /// assert_eq!(PARTS.0, "0042");
+    /// assert_eq!(PARTS.1, "Berlin");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `MID` is not on a char boundary.
+    pub const fn split_at<const MID: usize, const REST: usize>(&self) -> (AStr<MID>, AStr<REST>) {
+        match self.try_split_at::<MID, REST>() {
+            Ok(parts) => parts,
+            Err(_) => panic!("MID is not on a char boundary"),
+        }
     }
-}
 
-/// Private type to build an [`AStr`] from anything that can print to an [core::fmt::Write]
-struct FmtBuilder<const CAP: usize> {
-    len: usize,
-    partial: AStr<CAP>,
-}
+    /// Split into two [AStr]s at byte index `MID`.
+    ///
+    /// Fails to compile if `MID + REST` is not `LEN`.
+    /// Returns an error if `MID` is not on a char boundary.
+    pub const fn try_split_at<const MID: usize, const REST: usize>(
+        &self,
+    ) -> Result<(AStr<MID>, AStr<REST>), AStrError> {
+        const { const_assert(MID + REST == LEN, "MID + REST must equal LEN") };
 
-impl<const CAP: usize> FmtBuilder<CAP> {
-    pub fn new() -> Self {
-        Self {
-            len: 0,
-            partial: AStr::repeat('\0'),
+        if !is_char_boundary(&self.0, MID) {
+            return Err(AStrError::CharBoundary { index: MID });
         }
-    }
-
-    pub fn finalize(self) -> Result<AStr<CAP>, core::fmt::Error> {
-        if self.len == CAP {
-            Ok(self.partial)
-        } else {
-            Err(core::fmt::Error)
+        let (head, tail) = self.0.split_at(MID);
+        // SAFETY: both parts are cut at a char boundary
+        unsafe {
+            Ok((
+                *AStr::from_utf8_unchecked(head),
+                *AStr::from_utf8_unchecked(tail),
+            ))
         }
     }
-}
 
-fn str_copy_from_slice(dest: &mut str, src: &str) {
-    // SAFETY: `dest` and `src` are both valid string slices
-    unsafe {
-        dest.as_bytes_mut().copy_from_slice(src.as_bytes());
+    /// Get the sub str of `SUB_LEN` bytes starting at byte index `START`.
+    ///
+    /// Fails to compile if `START + SUB_LEN` is larger than `LEN`.
+    ///
+    /// ```rust
+    /// use astr::{astr, AStr};
+    ///
+    /// let record = astr!("0042Berlin");
+    /// let city: &AStr<6> = record.sub::<4, 6>();
+    /// assert_eq!(city, "Berlin");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `START` or `START + SUB_LEN` is not on a char boundary.
+    pub const fn sub<const START: usize, const SUB_LEN: usize>(&self) -> &AStr<SUB_LEN> {
+        match self.try_sub::<START, SUB_LEN>() {
+            Ok(sub) => sub,
+            Err(_) => panic!("sub str is not on a char boundary"),
+        }
     }
-}
 
-impl<const CAP: usize> core::fmt::Write for FmtBuilder<CAP> {
-    fn write_str(&mut self, s: &str) -> core::fmt::Result {
-        let s_len = s.len();
-        let offset = self.len;
+    /// Get the sub str of `SUB_LEN` bytes starting at byte index `START`.
+    ///
+    /// Fails to compile if `START + SUB_LEN` is larger than `LEN`.
+    /// Returns an error if `START` or `START + SUB_LEN` is not on a char boundary.
+    pub const fn try_sub<const START: usize, const SUB_LEN: usize>(
+        &self,
+    ) -> Result<&AStr<SUB_LEN>, AStrError> {
+        const { const_assert(START + SUB_LEN <= LEN, "START + SUB_LEN is out of bounds") };
 
-        let new_len = self.len.checked_add(s_len).ok_or(core::fmt::Error)?;
+        if !is_char_boundary(&self.0, START) {
+            return Err(AStrError::CharBoundary { index: START });
+        }
+        if !is_char_boundary(&self.0, START + SUB_LEN) {
+            return Err(AStrError::CharBoundary {
+                index: START + SUB_LEN,
+            });
+        }
+        let bytes = self.0.split_at(START).1.split_at(SUB_LEN).0;
+        // SAFETY: bytes is cut at char boundaries and SUB_LEN long
+        Ok(unsafe { AStr::from_utf8_unchecked(bytes) })
+    }
 
-        let rest = self.partial.get_mut(offset..).ok_or(core::fmt::Error)?;
-        let rest_bounded = rest.get_mut(..s_len).ok_or(core::fmt::Error)?;
+    /// Build an [AStr] from a an implementation of [`Display`][core::fmt::Display]
+    ///
+    /// See also [`format_astr!`] for a more convenient syntax.
+    ///
+    /// # Error
+    ///
+    /// Because an [AStr] has a fixed length, `display` must write exactly the expected number of
+    /// bytes to its `Formatter`, or this function will return an [`AStrError::Fmt`].
+    #[doc(alias = "format", alias = "display")]
+    pub fn try_from_fmt(display: impl core::fmt::Display) -> Result<Self, AStrError> {
+        use core::fmt::Write;
+        let mut builder = AStrBuilder::new();
+        let result = write!(builder, "{}", display);
+        builder.finish_fmt(result)
+    }
 
-        str_copy_from_slice(rest_bounded, s);
-        self.len = new_len;
+    /// Build an [AStr] from [`format_args!`], this is what [`format_astr!`] expands to.
+    ///
+    /// Format strings without arguments are copied directly, without going through the
+    /// formatting machinery.
+    ///
+    /// # Error
+    ///
+    /// Same as [`try_from_fmt`](Self::try_from_fmt).
+    pub fn try_from_args(args: core::fmt::Arguments<'_>) -> Result<Self, AStrError> {
+        let mut builder = AStrBuilder::new();
+        let result = match args.as_str() {
+            Some(s) => {
+                builder.push_str(s);
+                Ok(())
+            }
+            None => core::fmt::Write::write_fmt(&mut builder, args),
+        };
+        builder.finish_fmt(result)
+    }
 
-        Ok(())
+    /// Build an [AStr] from chars.
+    ///
+    /// ```rust
+    /// use astr::AStr;
+    ///
+    /// let s = AStr::<5>::try_from_chars("hello".chars().rev()).unwrap();
+    /// assert_eq!(s, "olleh");
+    /// ```
+    ///
+    /// # Error
+    ///
+    /// Returns an [`AStrError::Fmt`] if the chars are not exactly `LEN` bytes long. Iteration
+    /// stops at the first char that does not fit.
+    pub fn try_from_chars(chars: impl IntoIterator<Item = char>) -> Result<Self, AStrError> {
+        let mut builder = AStrBuilder::new();
+        for c in chars {
+            if builder.push(c).is_overflowed() {
+                break;
+            }
+        }
+        builder.finish()
     }
 }
 
@@ -536,34 +1057,74 @@ impl<const LEN: usize> core::str::FromStr for AStr<LEN> {
     }
 }
 
-impl<const LEN: usize> PartialEq<str> for AStr<LEN> {
-    fn eq(&self, other: &str) -> bool {
-        self.as_str().eq(other)
+impl<const LEN: usize, const B_LEN: usize> PartialEq<AStr<B_LEN>> for AStr<LEN> {
+    fn eq(&self, other: &AStr<B_LEN>) -> bool {
+        self.as_bytes()[..] == other.as_bytes()[..]
     }
 }
 
-impl<const LEN: usize> PartialEq<AStr<LEN>> for &AStr<LEN> {
-    fn eq(&self, other: &AStr<LEN>) -> bool {
-        AStr::<LEN>::eq(self, other)
+impl<const LEN: usize> Eq for AStr<LEN> {}
+
+impl<const LEN: usize, const B_LEN: usize> PartialOrd<AStr<B_LEN>> for AStr<LEN> {
+    fn partial_cmp(&self, other: &AStr<B_LEN>) -> Option<core::cmp::Ordering> {
+        Some(self.as_bytes()[..].cmp(&other.as_bytes()[..]))
     }
 }
 
-impl<const LEN: usize> PartialEq<&'_ str> for AStr<LEN> {
-    fn eq(&self, other: &&'_ str) -> bool {
-        self.eq(*other)
+impl<const LEN: usize> Ord for AStr<LEN> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_bytes().cmp(other.as_bytes())
     }
 }
 
-impl<const LEN: usize> PartialEq<AStr<LEN>> for str {
-    fn eq(&self, other: &AStr<LEN>) -> bool {
-        self.eq(other.as_str())
+impl<const LEN: usize, const B_LEN: usize> PartialEq<AStr<B_LEN>> for &AStr<LEN> {
+    fn eq(&self, other: &AStr<B_LEN>) -> bool {
+        PartialEq::eq(*self, other)
     }
 }
 
-impl<const LEN: usize> PartialEq<AStr<LEN>> for &'_ str {
-    fn eq(&self, other: &AStr<LEN>) -> bool {
-        (*self).eq(other)
-    }
+/// `PartialEq` and `PartialOrd` in both directions, comparing `$other` by its bytes.
+macro_rules! impl_cmp {
+    ($([$($generics:tt)*] $ty:ty, $other:ident => $bytes:expr;)*) => {$(
+        impl<const LEN: usize, $($generics)*> PartialEq<$ty> for AStr<LEN> {
+            fn eq(&self, $other: &$ty) -> bool {
+                self.as_bytes()[..] == *$bytes
+            }
+        }
+
+        impl<const LEN: usize, $($generics)*> PartialEq<AStr<LEN>> for $ty {
+            fn eq(&self, other: &AStr<LEN>) -> bool {
+                PartialEq::eq(other, self)
+            }
+        }
+
+        impl<const LEN: usize, $($generics)*> PartialOrd<$ty> for AStr<LEN> {
+            fn partial_cmp(&self, $other: &$ty) -> Option<core::cmp::Ordering> {
+                Some(self.as_bytes()[..].cmp($bytes))
+            }
+        }
+
+        impl<const LEN: usize, $($generics)*> PartialOrd<AStr<LEN>> for $ty {
+            fn partial_cmp(&self, other: &AStr<LEN>) -> Option<core::cmp::Ordering> {
+                PartialOrd::partial_cmp(other, self).map(core::cmp::Ordering::reverse)
+            }
+        }
+    )*};
+}
+
+impl_cmp! {
+    [] str, s => s.as_bytes();
+    [] &str, s => s.as_bytes();
+    [] [u8], s => s;
+    [] &[u8], s => *s;
+    [const N: usize] [u8; N], s => s.as_slice();
+}
+
+#[cfg(feature = "alloc")]
+impl_cmp! {
+    [] alloc::string::String, s => s.as_bytes();
+    [] alloc::borrow::Cow<'_, str>, s => s.as_bytes();
+    [] alloc::boxed::Box<str>, s => s.as_bytes();
 }
 
 impl<I: core::slice::SliceIndex<str>, const LEN: usize> core::ops::Index<I> for AStr<LEN> {
@@ -594,18 +1155,57 @@ impl<const LEN: usize> AsRef<std::path::Path> for AStr<LEN> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<const LEN: usize> From<AStr<LEN>> for String {
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> From<AStr<LEN>> for alloc::string::String {
     fn from(s: AStr<LEN>) -> Self {
         s.as_str().into()
     }
 }
 
-#[cfg(feature = "std")]
-impl<const LEN: usize> TryFrom<String> for AStr<LEN> {
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> TryFrom<alloc::string::String> for AStr<LEN> {
+    type Error = AStrError;
+
+    fn try_from(str: alloc::string::String) -> Result<Self, Self::Error> {
+        Ok(*AStr::try_from_str_ref(&str)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> From<AStr<LEN>> for alloc::boxed::Box<str> {
+    fn from(s: AStr<LEN>) -> Self {
+        s.as_str().into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> TryFrom<alloc::boxed::Box<str>> for AStr<LEN> {
+    type Error = AStrError;
+
+    fn try_from(str: alloc::boxed::Box<str>) -> Result<Self, Self::Error> {
+        Ok(*AStr::try_from_str_ref(&str)?)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, const LEN: usize> From<&'a AStr<LEN>> for alloc::borrow::Cow<'a, str> {
+    fn from(s: &'a AStr<LEN>) -> Self {
+        alloc::borrow::Cow::Borrowed(s.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> From<AStr<LEN>> for alloc::borrow::Cow<'_, str> {
+    fn from(s: AStr<LEN>) -> Self {
+        alloc::borrow::Cow::Owned(s.into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LEN: usize> TryFrom<alloc::borrow::Cow<'_, str>> for AStr<LEN> {
     type Error = AStrError;
 
-    fn try_from(str: String) -> Result<Self, Self::Error> {
+    fn try_from(str: alloc::borrow::Cow<'_, str>) -> Result<Self, Self::Error> {
         Ok(*AStr::try_from_str_ref(&str)?)
     }
 }
@@ -635,7 +1235,7 @@ mod serde_impl {
     impl<'de, const LEN: usize> Visitor<'de> for AStrVisitor<LEN> {
         type Value = AStr<LEN>;
 
-        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
             write!(formatter, "a string of length {}", LEN)
         }
 
@@ -652,6 +1252,51 @@ mod serde_impl {
     }
 }
 
+/// Serde impls for `#[derive(AStrNewtype)]`, empty without the `serde` feature.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_serde {
+    ($name:ty, $inner:ty) => {
+        impl $crate::__serde::Serialize for $name {
+            fn serialize<S: $crate::__serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::__serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::__serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<Self, D::Error> {
+                let value =
+                    <$inner as $crate::__serde::Deserialize<'de>>::deserialize(deserializer)?;
+                Self::try_new(value).map_err(<D::Error as $crate::__serde::de::Error>::custom)
+            }
+        }
+    };
+}
+
+/// Serde impls for `#[derive(AStrNewtype)]`, empty without the `serde` feature.
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __newtype_serde {
+    ($name:ty, $inner:ty) => {};
+}
+
+/// Compile time assertion for use in `const` blocks.
+///
+/// Unlike `assert!` this is a plain function call, which is also accepted in generic constants
+/// with the `nightly` feature.
+const fn const_assert(cond: bool, msg: &str) {
+    if !cond {
+        panic!("{}", msg)
+    }
+}
+
 const fn encode_utf8_raw(c: char) -> [u8; 4] {
     const TAG_CONT: u8 = 0b1000_0000;
     const TAG_TWO_B: u8 = 0b1100_0000;
@@ -686,7 +1331,7 @@ const fn encode_utf8_raw(c: char) -> [u8; 4] {
 
 #[cfg(test)]
 mod tests {
-    use super::{astr, AStr};
+    use super::{AStr, AStrError, FmtErrorKind};
 
     #[test]
     fn test_const() {
@@ -730,6 +1375,28 @@ mod tests {
         assert_eq!(s.to_string(), "hello");
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_alloc_conversions() {
+        use alloc::{borrow::Cow, boxed::Box, string::String};
+
+        let s = *astr!("hello");
+        assert_eq!(String::from(s), "hello");
+        assert_eq!(&*Box::<str>::from(s), "hello");
+        assert!(matches!(Cow::from(&s), Cow::Borrowed("hello")));
+        assert!(matches!(Cow::from(s), Cow::Owned(_)));
+
+        assert_eq!(AStr::<5>::try_from(Box::<str>::from("hello")).unwrap(), s);
+        assert_eq!(AStr::<5>::try_from(Cow::Borrowed("hello")).unwrap(), s);
+        assert_eq!(
+            AStr::<4>::try_from(String::from("hello")),
+            Err(AStrError::Length {
+                expected_len: 4,
+                actual_len: 5
+            })
+        );
+    }
+
     #[test]
     fn test_cstr() {
         let s = *astr!("hello\0");
@@ -738,6 +1405,22 @@ mod tests {
         assert_eq!(cstr.to_str().unwrap(), "hello");
     }
 
+    #[test]
+    fn test_repeat() {
+        const N: usize = 2;
+        const S: AStr<6> = astr!("äb"; N);
+        assert_eq!(S, "äbäb");
+        assert_eq!(astr!('x'; N + 1), "xxx");
+        assert_eq!(astr!(""; 4), "");
+        assert_eq!(AStr::<4>::repeat_str("ab"), "abab");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_repeat_str_len() {
+        AStr::<5>::repeat_str("ab");
+    }
+
     #[test]
     fn test_concat() {
         let a = astr!("hello");
@@ -747,6 +1430,58 @@ mod tests {
         assert_eq!(s, "hello world");
     }
 
+    #[test]
+    fn test_concat_macro() {
+        const A: AStr<5> = *astr!("hello");
+        const S: AStr<13> = astr_concat!(A, ',', " ", astr!("wörld"));
+        assert_eq!(S, "hello, wörld");
+        assert_eq!(astr_concat!(), "");
+        assert_eq!(astr_concat!("a", 'ä',), "aä");
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_concat_exact() {
+        let s = astr!("hello").concat_exact(astr!(" world"));
+        assert_eq!(s, "hello world");
+    }
+
+    #[test]
+    fn test_split_at() {
+        const S: AStr<6> = *astr!("abäcd");
+        const PARTS: (AStr<4>, AStr<2>) = S.split_at();
+        assert_eq!(PARTS, (*astr!("abä"), *astr!("cd")));
+
+        let (a, b) = S.split_at::<0, 6>();
+        assert_eq!((a, b), (*astr!(""), S));
+
+        assert_eq!(
+            S.try_split_at::<3, 3>(),
+            Err(AStrError::CharBoundary { index: 3 })
+        );
+    }
+
+    #[test]
+    fn test_sub() {
+        let s = astr!("abäcd");
+        assert_eq!(s.sub::<2, 2>(), "ä");
+        assert_eq!(s.sub::<4, 2>(), "cd");
+        assert_eq!(
+            s.try_sub::<3, 1>(),
+            Err(AStrError::CharBoundary { index: 3 })
+        );
+        assert_eq!(
+            s.try_sub::<1, 2>(),
+            Err(AStrError::CharBoundary { index: 3 })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_char_boundary() {
+        astr!("ä").split_at::<1, 1>();
+    }
+
     #[test]
     fn test_from_fmt() {
         let empty = AStr::<0>::try_from_fmt("").unwrap();
@@ -760,15 +1495,166 @@ mod tests {
     #[test]
     fn test_from_fmt_err() {
         let too_short = AStr::<16>::try_from_fmt("hello");
-        assert!(too_short.is_err());
+        assert_eq!(
+            too_short,
+            Err(AStrError::Fmt {
+                expected_len: 16,
+                kind: FmtErrorKind::TooShort { written: 5 }
+            })
+        );
 
         let too_long = AStr::<8>::try_from_fmt("hello world");
-        assert!(too_long.is_err());
+        assert_eq!(
+            too_long,
+            Err(AStrError::Fmt {
+                expected_len: 8,
+                kind: FmtErrorKind::Overflow
+            })
+        );
+
+        struct Failing;
+        impl core::fmt::Display for Failing {
+            fn fmt(&self, _: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                Err(core::fmt::Error)
+            }
+        }
+        assert_eq!(
+            AStr::<0>::try_from_fmt(Failing),
+            Err(AStrError::Fmt {
+                expected_len: 0,
+                kind: FmtErrorKind::Error
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            AStr::<3>::try_from("abcd"),
+            Err(AStrError::Length {
+                expected_len: 3,
+                actual_len: 4
+            })
+        );
+        assert_eq!(
+            AStr::<3>::try_from_utf8(b"a\xffb"),
+            Err(AStrError::Utf8 {
+                valid_up_to: 1,
+                error_len: Some(1)
+            })
+        );
+        assert_eq!(
+            AStr::<3>::try_from_utf8(b"ab\xc3"),
+            Err(AStrError::Utf8 {
+                valid_up_to: 2,
+                error_len: None
+            })
+        );
+        assert_eq!(
+            AStr::<3>::try_from_utf8(b"a\xffb").unwrap_err().to_string(),
+            "invalid utf-8 sequence of 1 bytes from index 1"
+        );
+        let slice_err = <[u8; 2]>::try_from(&b"abc"[..]).unwrap_err();
+        assert_eq!(AStrError::from(slice_err), AStrError::Slice);
+    }
+
+    #[test]
+    fn test_const_constructors() {
+        const FROM_STR: AStr<3> = AStr::from_str_const("abc");
+        const FROM_BYTES: AStr<3> = AStr::from_bytes_const("aä".as_bytes().first_chunk().unwrap());
+        const CHECKED: Result<&AStr<2>, AStrError> = AStr::try_from_utf8(b"\xffa");
+        const WRONG_LEN: Result<&AStr<2>, AStrError> = AStr::try_from_str_ref("abc");
+        assert_eq!(FROM_STR, "abc");
+        assert_eq!(FROM_BYTES, "aä");
+        assert_eq!(
+            CHECKED,
+            Err(AStrError::Utf8 {
+                valid_up_to: 0,
+                error_len: Some(1)
+            })
+        );
+        assert_eq!(
+            WRONG_LEN,
+            Err(AStrError::Length {
+                expected_len: 2,
+                actual_len: 3
+            })
+        );
+
+        let mut bytes = *b"ab";
+        const fn upper(bytes: &mut [u8; 2]) -> &mut AStr<2> {
+            let s = AStr::from_utf8_mut(bytes);
+            s.0[0] = b'A';
+            s
+        }
+        assert_eq!(*upper(&mut bytes), "Ab");
+    }
+
+    #[test]
+    #[should_panic = "the input is not valid UTF-8"]
+    fn test_from_bytes_const_panic() {
+        let _ = AStr::<1>::from_bytes_const(&[0xff]);
+    }
+
+    #[test]
+    fn test_cmp() {
+        let abc = *astr!("abc");
+        let ab = *astr!("ab");
+        let b = *astr!("b");
+        assert_ne!(abc, ab);
+        assert_ne!(ab, abc);
+        assert_eq!(abc, *astr!("abc"));
+        assert_eq!(&abc, abc);
+        assert!(ab < abc && abc < b && b > ab);
+        assert_eq!(abc.partial_cmp(&b), "abc".partial_cmp("b"));
+
+        assert_eq!(abc, "abc"[..]);
+        assert_eq!("abc"[..], abc);
+        assert!(abc < "abd"[..] && "b"[..] > abc);
+        assert_eq!(abc, b"abc"[..]);
+        assert_eq!(b"abc"[..], abc);
+        assert_eq!(abc, &b"abc"[..]);
+        assert_eq!(abc, *b"abc");
+        assert!(abc > b"ab"[..] && *b"abd" > abc);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_cmp_alloc() {
+        use alloc::{borrow::Cow, boxed::Box, string::String};
+
+        let abc = *astr!("abc");
+        assert_eq!(abc, String::from("abc"));
+        assert_eq!(String::from("abc"), abc);
+        let (abd, ab) = (String::from("abd"), String::from("ab"));
+        assert!(abc < abd && ab < abc);
+        assert_eq!(abc, Cow::Borrowed("abc"));
+        assert_eq!(Cow::<str>::Owned("abc".into()), abc);
+        assert_eq!(abc, Box::<str>::from("abc"));
+        let b = Box::<str>::from("b");
+        assert!(b > abc);
     }
 
     #[test]
     fn test_format_astr() {
         let salmon_str: AStr<6> = format_astr!("{:06X}", 0xFA8072u32).unwrap();
         assert_eq!(salmon_str, "FA8072");
+
+        let literal: AStr<5> = format_astr!("hello").unwrap();
+        assert_eq!(literal, "hello");
+        assert_eq!(
+            format_astr!("hello") as Result<AStr<6>, _>,
+            Err(AStrError::Fmt {
+                expected_len: 6,
+                kind: FmtErrorKind::TooShort { written: 5 }
+            })
+        );
+        assert_eq!(
+            format_astr!("hello {}", "world") as Result<AStr<8>, _>,
+            Err(AStrError::Fmt {
+                expected_len: 8,
+                kind: FmtErrorKind::Overflow
+            })
+        );
     }
 }
@@ -0,0 +1,310 @@
+use crate::{floor_char_boundary, AStr, AStrBuilder, AStrError};
+
+/// Where to place a str that is shorter than the [AStr] it is padded into.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Place the str at the start, pad at the end.
+    #[default]
+    Left,
+    /// Place the str at the end, pad at the start.
+    Right,
+    /// Place the str in the middle, an odd pad char goes to the end.
+    Center,
+}
+
+/// Output of [`format_astr_padded!`](crate::format_astr_padded) and
+/// [`format_astr_truncated!`](crate::format_astr_truncated).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Formatted<const LEN: usize> {
+    /// The padded or truncated output.
+    pub value: AStr<LEN>,
+    /// `true` if the output was cut off, `false` if it was padded or fit exactly.
+    pub truncated: bool,
+}
+
+/// Keeps the longest prefix of the output that fits, stops formatting after that.
+struct Truncating<const LEN: usize> {
+    builder: AStrBuilder<LEN>,
+    truncated: bool,
+}
+
+impl<const LEN: usize> core::fmt::Write for Truncating<LEN> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if self.truncated {
+            return Err(core::fmt::Error);
+        }
+        if s.len() <= self.builder.remaining() {
+            self.builder.push_str(s);
+            Ok(())
+        } else {
+            self.builder
+                .push_str(&s[..floor_char_boundary(s.as_bytes(), self.builder.remaining())]);
+            self.truncated = true;
+            Err(core::fmt::Error)
+        }
+    }
+}
+
+impl<const LEN: usize> AStr<LEN> {
+    /// Build an [AStr] from [`format_args!`], padding or truncating the output to fit.
+    ///
+    /// This is what [`format_astr_padded!`](crate::format_astr_padded) and
+    /// [`format_astr_truncated!`](crate::format_astr_truncated) expand to. Output that is too
+    /// long is cut at the last char boundary that fits, the remaining bytes are filled with
+    /// `pad` according to `align`. If the [`Display`](core::fmt::Display) implementation of an
+    /// argument fails, the output written up to then is used.
+    ///
+    /// If the missing bytes are not a multiple of the pad char utf8 len, the bytes left over are
+    /// filled with `' '`.
+    pub fn from_args_padded(
+        pad: char,
+        align: Align,
+        args: core::fmt::Arguments<'_>,
+    ) -> Formatted<LEN> {
+        let mut writer = Truncating::<LEN> {
+            builder: AStrBuilder::new(),
+            truncated: false,
+        };
+        let _ = match args.as_str() {
+            Some(s) => core::fmt::Write::write_str(&mut writer, s),
+            None => core::fmt::Write::write_fmt(&mut writer, args),
+        };
+        Formatted {
+            value: Self::fill(writer.builder.as_str(), pad, align),
+            truncated: writer.truncated,
+        }
+    }
+
+    /// Create a new AStr from a str that is at most LEN bytes long, filling the rest with `pad`.
+    ///
+    /// Fails if the str is too long or the missing bytes are not a multiple of the pad char utf8
+    /// len.
+    ///
+    /// ```rust
+    /// use astr::{AStr, Align};
+    ///
+    /// let s = AStr::<8>::from_str_padded("42", '0', Align::Right).unwrap();
+    /// assert_eq!(s, "00000042");
+    /// assert!(AStr::<3>::from_str_padded("ab", 'ä', Align::Left).is_err());
+    /// ```
+    pub fn from_str_padded(s: &str, pad: char, align: Align) -> Result<Self, AStrError> {
+        if s.len() > LEN {
+            return Err(AStrError::Length {
+                expected_len: LEN,
+                actual_len: s.len(),
+            });
+        }
+        let gap = LEN - s.len();
+        if gap % pad.len_utf8() != 0 {
+            return Err(AStrError::Padding {
+                gap,
+                pad_len: pad.len_utf8(),
+            });
+        }
+        Ok(Self::fill(s, pad, align))
+    }
+
+    /// Create a new AStr from a str, cutting it at the last char boundary that fits or
+    /// filling the rest with `pad`.
+    ///
+    /// If the missing bytes are not a multiple of the pad char utf8 len, the bytes left over are
+    /// filled with `' '`.
+    ///
+    /// ```rust
+    /// use astr::AStr;
+    ///
+    /// let s = AStr::<4>::from_str_truncated("Käse", ' ');
+    /// assert_eq!(s, "Käs");
+    /// ```
+    pub fn from_str_truncated(s: &str, pad: char) -> Self {
+        Self::fill(
+            &s[..floor_char_boundary(s.as_bytes(), LEN)],
+            pad,
+            Align::Left,
+        )
+    }
+
+    /// Convert to an AStr of a different length, truncating or padding at the end as needed.
+    ///
+    /// Like [`from_str_truncated`](Self::from_str_truncated), bytes the pad char does not fit
+    /// into are filled with `' '`.
+    ///
+    /// ```rust
+    /// use astr::{astr, AStr};
+    ///
+    /// let s: AStr<6> = astr!("abc").resize('-');
+    /// assert_eq!(s, "abc---");
+    /// ```
+    pub fn resize<const NEW_LEN: usize>(&self, pad: char) -> AStr<NEW_LEN> {
+        AStr::from_str_truncated(self.as_str(), pad)
+    }
+
+    /// Get the str without the leading and trailing `pad` chars.
+    ///
+    /// For a multi-byte `pad`, the `' '` filling the bytes left over at the edges is removed as
+    /// well.
+    ///
+    /// ```rust
+    /// use astr::{astr, AStr};
+    ///
+    /// assert_eq!(astr!("  name  ").trim_padding(' '), "name");
+    /// assert_eq!(AStr::<5>::from_str_truncated("ab", 'ä').trim_padding('ä'), "ab");
+    /// ```
+    pub fn trim_padding(&self, pad: char) -> &str {
+        let filler = pad.len_utf8() - 1;
+        let s = self.as_str();
+        let start = s.bytes().take(filler).take_while(|&b| b == b' ').count();
+        let s = &s[start..];
+        let end = s.len()
+            - s.bytes()
+                .rev()
+                .take(filler)
+                .take_while(|&b| b == b' ')
+                .count();
+        s[..end].trim_matches(pad)
+    }
+
+    /// Place `s` according to `align` and fill the rest with `pad`. Bytes left over because the
+    /// gap is not a multiple of the pad char utf8 len are filled with `' '` at the outer edge,
+    /// where [`trim_padding`](Self::trim_padding) removes them.
+    fn fill(s: &str, pad: char, align: Align) -> Self {
+        let mut pad_buf = [0; 4];
+        let pad = pad.encode_utf8(&mut pad_buf).as_bytes();
+        let gap = LEN - s.len();
+
+        let pad_count = gap / pad.len();
+        let before = match align {
+            Align::Left => 0,
+            Align::Right => pad_count,
+            Align::Center => pad_count / 2,
+        };
+        let spare = match align {
+            Align::Right => gap % pad.len(),
+            Align::Left | Align::Center => 0,
+        };
+
+        let mut bytes = [b' '; LEN];
+        let (_, rest) = bytes.split_at_mut(spare);
+        let (head, rest) = rest.split_at_mut(before * pad.len());
+        let (mid, rest) = rest.split_at_mut(s.len());
+        let (tail, _) = rest.split_at_mut((pad_count - before) * pad.len());
+        head.chunks_exact_mut(pad.len())
+            .for_each(|c| c.copy_from_slice(pad));
+        mid.copy_from_slice(s.as_bytes());
+        tail.chunks_exact_mut(pad.len())
+            .for_each(|c| c.copy_from_slice(pad));
+
+        // SAFETY: s, pad and the spare spaces are valid UTF-8 and written as a whole
+        unsafe { Self::from_utf8_array_unchecked(bytes) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Align, Formatted};
+    use crate::{AStr, AStrError};
+
+    #[test]
+    fn test_padded() {
+        let left = AStr::<6>::from_str_padded("ab", ' ', Align::Left).unwrap();
+        assert_eq!(left, "ab    ");
+        let right = AStr::<6>::from_str_padded("ab", ' ', Align::Right).unwrap();
+        assert_eq!(right, "    ab");
+        let center = AStr::<5>::from_str_padded("ab", '*', Align::Center).unwrap();
+        assert_eq!(center, "*ab**");
+        let wide = AStr::<6>::from_str_padded("ab", 'ä', Align::Right).unwrap();
+        assert_eq!(wide, "ääab");
+
+        assert!(AStr::<2>::from_str_padded("abc", ' ', Align::Left).is_err());
+    }
+
+    #[test]
+    fn test_padded_odd_gap() {
+        assert_eq!(
+            AStr::<5>::from_str_padded("ab", 'ä', Align::Left),
+            Err(AStrError::Padding { gap: 3, pad_len: 2 })
+        );
+
+        let left = AStr::<5>::from_str_truncated("ab", 'ä');
+        assert_eq!(left, "abä ");
+        assert_eq!(left.trim_padding('ä'), "ab");
+        let right = AStr::<5>::from_args_padded('ä', Align::Right, format_args!("ab")).value;
+        assert_eq!(right, " äab");
+        assert_eq!(right.trim_padding('ä'), "ab");
+        let center = AStr::<8>::from_args_padded('€', Align::Center, format_args!("a")).value;
+        assert_eq!(center, "€a€ ");
+        assert_eq!(center.trim_padding('€'), "a");
+        assert_eq!(AStr::<2>::from_str_truncated("", '€').trim_padding('€'), "");
+    }
+
+    #[test]
+    fn test_truncated() {
+        assert_eq!(AStr::<3>::from_str_truncated("aää", '_'), "aä");
+        assert_eq!(AStr::<4>::from_str_truncated("aää", '_'), "aä_");
+        assert_eq!(AStr::<6>::from_str_truncated("aää", '_'), "aää_");
+        assert_eq!(AStr::<4>::from_str_truncated("aää", 'ä'), "aä ");
+        assert_eq!(AStr::<8>::from_str_truncated("aää", 'ä'), "aäää ");
+    }
+
+    #[test]
+    fn test_resize() {
+        let s = crate::astr!("hällo");
+        assert_eq!(s.resize::<2>(' '), "h ");
+        assert_eq!(s.resize::<8>(' '), "hällo  ");
+        assert_eq!(s.resize::<8>(' ').trim_padding(' '), "hällo");
+    }
+
+    #[test]
+    fn test_format_padded() {
+        let s: Formatted<6> = crate::format_astr_padded!('0', Align::Right, "{}", 42);
+        assert_eq!(s.value, "000042");
+        assert!(!s.truncated);
+
+        let s: Formatted<6> = crate::format_astr_padded!('*', Align::Center, "{}-{}", "ab", 'c');
+        assert_eq!(s.value, "*ab-c*");
+
+        let s: Formatted<4> = crate::format_astr_padded!(' ', Align::Right, "{}{}", "aä", "äb");
+        assert_eq!(s.value, " aä");
+        assert!(s.truncated);
+
+        let s: Formatted<4> = crate::format_astr_padded!('·', Align::Left, "{}", "abc");
+        assert_eq!(s.value, "abc ");
+        assert!(!s.truncated);
+        let s: Formatted<6> = crate::format_astr_padded!('·', Align::Right, "{}", "abc");
+        assert_eq!(s.value, " ·abc");
+    }
+
+    #[test]
+    fn test_format_truncated_ignored_error() {
+        // writes after the cut must not end up in the output
+        struct IgnoresErrors;
+
+        impl core::fmt::Display for IgnoresErrors {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let _ = f.write_str("aä");
+                f.write_str("b")
+            }
+        }
+
+        let s: Formatted<2> = crate::format_astr_truncated!("{}", IgnoresErrors);
+        assert_eq!(s.value, "a ");
+        assert!(s.truncated);
+    }
+
+    #[test]
+    fn test_format_truncated() {
+        let s: Formatted<5> = crate::format_astr_truncated!("{}: {}", "name", 42);
+        assert_eq!(s.value, "name:");
+        assert!(s.truncated);
+
+        let s: Formatted<5> = crate::format_astr_truncated!("Käse");
+        assert_eq!(s.value, "Käse");
+        assert!(!s.truncated);
+
+        let s: Formatted<4> = crate::format_astr_truncated!("Käsebrot");
+        assert_eq!(s.value, "Käs");
+        let s: Formatted<2> = crate::format_astr_truncated!("Käsebrot");
+        assert_eq!(s.value, "K ");
+        assert!(s.truncated);
+    }
+}
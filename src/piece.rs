@@ -0,0 +1,101 @@
+use crate::AStr;
+
+/// Gives chars, strs and [AStr]s a common const interface for use in macros.
+///
+/// The methods are inherent const fns on each concrete wrapper, so the macros can call them on
+/// any supported input without const traits.
+pub struct Piece<T>(pub T);
+
+impl Piece<char> {
+    pub const fn len_utf8(&self) -> usize {
+        self.0.len_utf8()
+    }
+
+    pub const fn repeat<const LEN: usize>(&self) -> AStr<LEN> {
+        AStr::repeat(self.0)
+    }
+}
+
+impl Piece<&str> {
+    pub const fn len_utf8(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn repeat<const LEN: usize>(&self) -> AStr<LEN> {
+        AStr::repeat_str(self.0)
+    }
+}
+
+impl<const N: usize> Piece<AStr<N>> {
+    pub const fn len_utf8(&self) -> usize {
+        N
+    }
+
+    pub const fn repeat<const LEN: usize>(&self) -> AStr<LEN> {
+        AStr::repeat_str(self.0.as_str())
+    }
+}
+
+impl<const N: usize> Piece<&AStr<N>> {
+    pub const fn len_utf8(&self) -> usize {
+        N
+    }
+
+    pub const fn repeat<const LEN: usize>(&self) -> AStr<LEN> {
+        AStr::repeat_str(self.0.as_str())
+    }
+}
+
+/// Buffer that the pieces of `astr_concat!` are appended to in a const context.
+pub struct Concat<const LEN: usize> {
+    bytes: [u8; LEN],
+    len: usize,
+}
+
+impl<const LEN: usize> Concat<LEN> {
+    pub const EMPTY: Self = Self {
+        bytes: [0; LEN],
+        len: 0,
+    };
+
+    const fn push_bytes(mut self, bytes: &[u8]) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.bytes[self.len + i] = bytes[i];
+            i += 1;
+        }
+        self.len += bytes.len();
+        self
+    }
+
+    pub const fn finish(self) -> AStr<LEN> {
+        assert!(self.len == LEN, "astr_concat length mismatch");
+        // SAFETY: only whole chars and strs were appended
+        unsafe { AStr::from_utf8_array_unchecked(self.bytes) }
+    }
+}
+
+impl Piece<char> {
+    pub const fn append_to<const LEN: usize>(&self, buf: Concat<LEN>) -> Concat<LEN> {
+        let bytes = crate::encode_utf8_raw(self.0);
+        buf.push_bytes(bytes.split_at(self.0.len_utf8()).0)
+    }
+}
+
+impl Piece<&str> {
+    pub const fn append_to<const LEN: usize>(&self, buf: Concat<LEN>) -> Concat<LEN> {
+        buf.push_bytes(self.0.as_bytes())
+    }
+}
+
+impl<const N: usize> Piece<AStr<N>> {
+    pub const fn append_to<const LEN: usize>(&self, buf: Concat<LEN>) -> Concat<LEN> {
+        buf.push_bytes(self.0.as_slice())
+    }
+}
+
+impl<const N: usize> Piece<&AStr<N>> {
+    pub const fn append_to<const LEN: usize>(&self, buf: Concat<LEN>) -> Concat<LEN> {
+        buf.push_bytes(self.0.as_slice())
+    }
+}
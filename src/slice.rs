@@ -0,0 +1,156 @@
+use crate::{const_assert, is_char_boundary, AStr, AStrError};
+
+impl<const LEN: usize> AStr<LEN> {
+    /// View a buffer of packed records as a slice of [AStr]s, without copying.
+    ///
+    /// The whole buffer is validated once, every record must start on a char boundary.
+    ///
+    /// ```rust
+    /// use astr::AStr;
+    ///
+    /// let records = AStr::<4>::slice_from_utf8("0042abcdäö".as_bytes()).unwrap();
+    /// assert_eq!(records, ["0042", "abcd", "äö"]);
+    /// ```
+    ///
+    /// Fails to compile if LEN is zero.
+    pub fn slice_from_utf8(bytes: &[u8]) -> Result<&[Self], AStrError> {
+        Self::check_records(bytes)?;
+        Ok(unsafe { Self::slice_from_utf8_unchecked(bytes) })
+    }
+
+    /// View a buffer of packed records as a mutable slice of [AStr]s, without copying.
+    ///
+    /// Fails to compile if LEN is zero.
+    pub fn slice_from_utf8_mut(bytes: &mut [u8]) -> Result<&mut [Self], AStrError> {
+        Self::check_records(bytes)?;
+        Ok(unsafe { Self::slice_from_utf8_unchecked_mut(bytes) })
+    }
+
+    /// View a buffer of packed records as a slice of [AStr]s, without copying.
+    /// # Safety
+    /// The buffer must be valid UTF-8, its length a multiple of LEN and every multiple of LEN
+    /// must be a char boundary.
+    pub const unsafe fn slice_from_utf8_unchecked(bytes: &[u8]) -> &[Self] {
+        const { const_assert(LEN > 0, "LEN must not be zero") };
+        debug_assert!(bytes.len() % LEN == 0);
+        core::slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len() / LEN)
+    }
+
+    /// View a buffer of packed records as a mutable slice of [AStr]s, without copying.
+    /// # Safety
+    /// The buffer must be valid UTF-8, its length a multiple of LEN and every multiple of LEN
+    /// must be a char boundary.
+    pub unsafe fn slice_from_utf8_unchecked_mut(bytes: &mut [u8]) -> &mut [Self] {
+        const { const_assert(LEN > 0, "LEN must not be zero") };
+        debug_assert!(bytes.len() % LEN == 0);
+        core::slice::from_raw_parts_mut(bytes.as_mut_ptr().cast(), bytes.len() / LEN)
+    }
+
+    /// View a slice of [AStr]s as one str.
+    ///
+    /// ```rust
+    /// use astr::{astr, AStr};
+    ///
+    /// let records = [*astr!("ab"), *astr!("cd")];
+    /// assert_eq!(AStr::slice_as_str(&records), "abcd");
+    /// ```
+    pub const fn slice_as_str(slice: &[Self]) -> &str {
+        // SAFETY: AStr is a transparent wrapper around valid UTF-8 bytes without padding
+        unsafe {
+            core::str::from_utf8_unchecked(core::slice::from_raw_parts(
+                slice.as_ptr().cast(),
+                slice.len() * LEN,
+            ))
+        }
+    }
+
+    /// Iterate over a str in chunks of exactly LEN bytes.
+    ///
+    /// Fails if the str length is not a multiple of LEN or a chunk would split a char.
+    ///
+    /// ```rust
+    /// use astr::AStr;
+    ///
+    /// let mut chunks = AStr::<3>::chunks_of("abcdef").unwrap();
+    /// assert_eq!(chunks.next().unwrap(), "abc");
+    /// assert_eq!(chunks.next().unwrap(), "def");
+    /// assert!(AStr::<2>::chunks_of("aäb").is_err());
+    /// ```
+    ///
+    /// Fails to compile if LEN is zero.
+    pub fn chunks_of(s: &str) -> Result<core::slice::Iter<'_, Self>, AStrError> {
+        Self::check_boundaries(s.as_bytes())?;
+        Ok(unsafe { Self::slice_from_utf8_unchecked(s.as_bytes()) }.iter())
+    }
+
+    fn check_records(bytes: &[u8]) -> Result<(), AStrError> {
+        core::str::from_utf8(bytes)?;
+        Self::check_boundaries(bytes)
+    }
+
+    /// Check the length and the char boundaries of already valid UTF-8.
+    fn check_boundaries(bytes: &[u8]) -> Result<(), AStrError> {
+        if bytes.len() % LEN != 0 {
+            return Err(AStrError::PartialRecord {
+                record_len: LEN,
+                actual_len: bytes.len(),
+            });
+        }
+        match (0..bytes.len() / LEN).find(|&i| !is_char_boundary(bytes, i * LEN)) {
+            Some(i) => Err(AStrError::CharBoundary { index: i * LEN }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{astr, AStr, AStrError};
+
+    #[test]
+    fn test_slice_from_utf8() {
+        let records = AStr::<3>::slice_from_utf8("abcäbxyz".as_bytes()).unwrap();
+        assert_eq!(records, [*astr!("abc"), *astr!("äb"), *astr!("xyz")]);
+        assert_eq!(AStr::slice_as_str(records), "abcäbxyz");
+
+        assert!(AStr::<3>::slice_from_utf8(b"").unwrap().is_empty());
+        assert_eq!(
+            AStr::<3>::slice_from_utf8(b"abcd"),
+            Err(AStrError::PartialRecord {
+                record_len: 3,
+                actual_len: 4
+            })
+        );
+        assert_eq!(
+            AStr::<3>::slice_from_utf8(b"abcd").unwrap_err().to_string(),
+            "4 bytes are not a multiple of the record length 3"
+        );
+        assert_eq!(
+            AStr::<3>::slice_from_utf8("abäcd".as_bytes()),
+            Err(AStrError::CharBoundary { index: 3 })
+        );
+        assert_eq!(
+            AStr::<3>::slice_from_utf8(b"ab\xff"),
+            Err(AStrError::Utf8 {
+                valid_up_to: 2,
+                error_len: Some(1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_slice_from_utf8_mut() {
+        let mut buf = *b"abcdef";
+        let records = AStr::<3>::slice_from_utf8_mut(&mut buf).unwrap();
+        records[1].make_ascii_uppercase();
+        assert_eq!(&buf, b"abcDEF");
+    }
+
+    #[test]
+    fn test_chunks_of() {
+        let chunks: Vec<_> = AStr::<2>::chunks_of("abäcd").unwrap().collect();
+        assert_eq!(chunks, ["ab", "ä", "cd"]);
+        assert!(AStr::<2>::chunks_of("abc").is_err());
+        assert!(AStr::<2>::chunks_of("aäb").is_err());
+    }
+}
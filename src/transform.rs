@@ -0,0 +1,277 @@
+use crate::AStr;
+use core::ops::RangeBounds;
+
+/// In-place transformations.
+///
+/// All of these keep the byte length `LEN` and only move or replace whole chars, so the str
+/// stays valid UTF-8 without any `unsafe` on the caller side.
+///
+/// ```rust
+/// use astr::astr;
+///
+/// let mut card = *astr!("4111 1111 1111 1234");
+/// card.mask(..14, '*');
+/// assert_eq!(card, "************** 1234");
+/// ```
+impl<const LEN: usize> AStr<LEN> {
+    /// Reverse the order of the chars, multi byte chars keep their encoding.
+    ///
+    /// This works on chars, combining marks end up before the char they belonged to.
+    ///
+    /// ```rust
+    /// use astr::astr;
+    ///
+    /// let mut s = *astr!("Käse");
+    /// s.reverse_chars();
+    /// assert_eq!(s, "esäK");
+    /// ```
+    pub fn reverse_chars(&mut self) {
+        self.0.reverse();
+        let mut start = 0;
+        for i in 0..LEN {
+            // after reversing, the continuation bytes of a char come before its lead byte
+            if self.0[i] & 0xC0 != 0x80 {
+                self.0[start..=i].reverse();
+                start = i + 1;
+            }
+        }
+    }
+
+    /// Rotate the str so that the char at index `n` comes first, like `slice::rotate_left`
+    /// for chars. The bytes are only moved as whole chars.
+    ///
+    /// ```rust
+    /// use astr::astr;
+    ///
+    /// let mut s = *astr!("äbc");
+    /// s.rotate_chars_left(1);
+    /// assert_eq!(s, "bcä");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `n` is greater than the number of chars.
+    pub fn rotate_chars_left(&mut self, n: usize) {
+        let mid = self.char_offset(n);
+        self.0.rotate_left(mid);
+    }
+
+    /// Rotate the str so that the last `n` chars come first, like `slice::rotate_right` for
+    /// chars. The bytes are only moved as whole chars.
+    ///
+    /// ```rust
+    /// use astr::astr;
+    ///
+    /// let mut s = *astr!("abä");
+    /// s.rotate_chars_right(1);
+    /// assert_eq!(s, "äab");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `n` is greater than the number of chars.
+    pub fn rotate_chars_right(&mut self, n: usize) {
+        let count = self.char_count();
+        assert!(n <= count, "n is greater than the number of chars");
+        let mid = self.char_offset(count - n);
+        self.0.rotate_left(mid);
+    }
+
+    /// Turn upper case ASCII letters into lower case ones and vice versa. Other chars, including
+    /// non ASCII letters, are not changed, so the length stays the same.
+    ///
+    /// ```rust
+    /// use astr::astr;
+    ///
+    /// let mut s = *astr!("aBäÖ");
+    /// s.swap_ascii_case();
+    /// assert_eq!(s, "AbäÖ");
+    /// ```
+    pub fn swap_ascii_case(&mut self) {
+        for b in &mut self.0 {
+            if b.is_ascii_alphabetic() {
+                *b ^= 0x20;
+            }
+        }
+    }
+
+    /// Rotate ASCII letters by 13 places, applying it twice gives back the input. Only ASCII
+    /// bytes are replaced, by ASCII bytes.
+    ///
+    /// ```rust
+    /// use astr::astr;
+    ///
+    /// let mut s = *astr!("Hello, Käse");
+    /// s.rot13();
+    /// assert_eq!(s, "Uryyb, Xäfr");
+    /// ```
+    pub fn rot13(&mut self) {
+        for b in &mut self.0 {
+            let base = match b {
+                b'a'..=b'z' => b'a',
+                b'A'..=b'Z' => b'A',
+                _ => continue,
+            };
+            *b = (*b - base + 13) % 26 + base;
+        }
+    }
+
+    /// Replace every `from` with `to` and return the number of replacements.
+    ///
+    /// Both chars must have the same UTF-8 length, so the byte length does not change.
+    ///
+    /// ```rust
+    /// use astr::astr;
+    ///
+    /// let mut s = *astr!("ä-ä-a");
+    /// assert_eq!(s.replace_char_same_width('ä', 'ö'), 2);
+    /// assert_eq!(s, "ö-ö-a");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the UTF-8 lengths of `from` and `to` differ.
+    pub fn replace_char_same_width(&mut self, from: char, to: char) -> usize {
+        assert!(
+            from.len_utf8() == to.len_utf8(),
+            "the chars have different utf8 lengths"
+        );
+        let (mut from_buf, mut to_buf) = ([0; 4], [0; 4]);
+        let from = from.encode_utf8(&mut from_buf).as_bytes();
+        let to = to.encode_utf8(&mut to_buf).as_bytes();
+
+        let mut count = 0;
+        let mut i = 0;
+        while i + from.len() <= LEN {
+            // an encoded char can only match at a char boundary of valid UTF-8
+            if &self.0[i..i + from.len()] == from {
+                self.0[i..i + from.len()].copy_from_slice(to);
+                count += 1;
+                i += from.len();
+            } else {
+                i += 1;
+            }
+        }
+        count
+    }
+
+    /// Overwrite the bytes in `range` with `mask`, e.g. to redact personal data before logging.
+    ///
+    /// The range is in bytes like `str` indexing, a multi byte char is replaced by as many mask
+    /// chars as it has bytes, so the length stays the same.
+    ///
+    /// ```rust
+    /// use astr::astr;
+    ///
+    /// let mut name = *astr!("Jörg Müller");
+    /// name.mask(1.., '*');
+    /// assert_eq!(name, "J************");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds or not on char boundaries, or if its length is
+    /// not a multiple of the mask char utf8 length.
+    pub fn mask(&mut self, range: impl RangeBounds<usize>, mask: char) {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        let target = &mut self.as_str_mut()[range];
+        let mut mask_buf = [0; 4];
+        let mask = mask.encode_utf8(&mut mask_buf).as_bytes();
+        assert!(
+            target.len() % mask.len() == 0,
+            "the masked range is not a multiple of the mask char utf8 length"
+        );
+        // SAFETY: the range is on char boundaries and is filled with whole chars
+        unsafe { target.as_bytes_mut() }
+            .chunks_exact_mut(mask.len())
+            .for_each(|c| c.copy_from_slice(mask));
+    }
+
+    /// Byte offset of the char at index `n`, or `LEN` if there are exactly `n` chars.
+    fn char_offset(&self, n: usize) -> usize {
+        match self.as_str().char_indices().nth(n) {
+            Some((offset, _)) => offset,
+            None if n == self.char_count() => LEN,
+            None => panic!("n is greater than the number of chars"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::astr;
+
+    #[test]
+    fn test_reverse_chars() {
+        macro_rules! check {
+            ($($s:literal),*) => {$(
+                let mut a = *astr!($s);
+                a.reverse_chars();
+                assert_eq!(a.as_str(), $s.chars().rev().collect::<String>());
+            )*};
+        }
+        check!("", "a", "ab", "Käsebrot", "ä€𝄞x");
+    }
+
+    #[test]
+    fn test_rotate_chars() {
+        let mut s = *astr!("ä€𝄞x");
+        s.rotate_chars_left(2);
+        assert_eq!(s, "𝄞xä€");
+        s.rotate_chars_right(3);
+        assert_eq!(s, "xä€𝄞");
+        s.rotate_chars_left(4);
+        assert_eq!(s, "xä€𝄞");
+        s.rotate_chars_right(0);
+        assert_eq!(s, "xä€𝄞");
+    }
+
+    #[test]
+    #[should_panic = "n is greater than the number of chars"]
+    fn test_rotate_chars_too_far() {
+        let mut s = *astr!("äb");
+        s.rotate_chars_left(3);
+    }
+
+    #[test]
+    fn test_ascii_transforms() {
+        let mut s = *astr!("Az-zA ä");
+        s.swap_ascii_case();
+        assert_eq!(s, "aZ-Za ä");
+        s.rot13();
+        assert_eq!(s, "nM-Mn ä");
+        s.rot13();
+        assert_eq!(s, "aZ-Za ä");
+    }
+
+    #[test]
+    fn test_replace_char_same_width() {
+        let mut s = *astr!("€a€€");
+        assert_eq!(s.replace_char_same_width('→', '←'), 0);
+        assert_eq!(s.replace_char_same_width('€', '→'), 3);
+        assert_eq!(s, "→a→→");
+        assert_eq!(s.replace_char_same_width('a', 'b'), 1);
+        assert_eq!(s, "→b→→");
+    }
+
+    #[test]
+    #[should_panic = "different utf8 lengths"]
+    fn test_replace_char_different_width() {
+        let mut s = *astr!("a");
+        s.replace_char_same_width('a', 'ä');
+    }
+
+    #[test]
+    fn test_mask() {
+        let mut s = *astr!("DE89 3704 0044");
+        s.mask(5..9, '#');
+        assert_eq!(s, "DE89 #### 0044");
+        s.mask(.., 'ä');
+        assert_eq!(s, "äääääää");
+        s.mask(2..4, '-');
+        assert_eq!(s, "ä--äääää");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mask_char_boundary() {
+        let mut s = *astr!("äb");
+        s.mask(1.., '*');
+    }
+}
@@ -0,0 +1,446 @@
+//! [AStr]s restricted to a set of bytes, see [Validated].
+
+use crate::{AStr, AStrError, DynFixedStr, FixedStr};
+use core::marker::PhantomData;
+
+/// A set of bytes that can be built and queried in `const` context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    /// The set without any bytes.
+    pub const EMPTY: Self = Self([0; 4]);
+
+    /// The set of the bytes from `start` to `end`, both inclusive.
+    pub const fn range(start: u8, end: u8) -> Self {
+        let mut set = Self::EMPTY;
+        let mut b = start as usize;
+        while b <= end as usize {
+            set.0[b / 64] |= 1 << (b % 64);
+            b += 1;
+        }
+        set
+    }
+
+    /// The set of the given bytes.
+    pub const fn bytes(bytes: &[u8]) -> Self {
+        let mut set = Self::EMPTY;
+        let mut i = 0;
+        while i < bytes.len() {
+            set.0[bytes[i] as usize / 64] |= 1 << (bytes[i] % 64);
+            i += 1;
+        }
+        set
+    }
+
+    /// The bytes that are in either set.
+    pub const fn union(self, other: Self) -> Self {
+        Self([
+            self.0[0] | other.0[0],
+            self.0[1] | other.0[1],
+            self.0[2] | other.0[2],
+            self.0[3] | other.0[3],
+        ])
+    }
+
+    /// Returns `true` if `byte` is in the set.
+    pub const fn contains(&self, byte: u8) -> bool {
+        self.0[byte as usize / 64] & (1 << (byte % 64)) != 0
+    }
+
+    /// Returns the index of the first byte that is not in the set.
+    pub const fn find_invalid(&self, bytes: &[u8]) -> Option<usize> {
+        let mut i = 0;
+        while i < bytes.len() {
+            if !self.contains(bytes[i]) {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
+/// Decides which bytes a [Validated] may hold.
+///
+/// ```rust
+/// use astr::{validate::{ByteSet, Validator}, validated};
+///
+/// struct Dna;
+///
+/// impl Validator for Dna {
+///     const ALLOWED: ByteSet = ByteSet::bytes(b"ACGT");
+///     const REASON: &'static str = "expected A, C, G or T";
+/// }
+///
+/// let seq = validated!(Dna, "GATTACA");
+/// assert_eq!(seq, "GATTACA");
+/// assert!("GATTAXA".parse::<astr::Validated<7, Dna>>().is_err());
+/// ```
+pub trait Validator {
+    /// The allowed bytes, strs are still required to be valid UTF-8.
+    const ALLOWED: ByteSet;
+    /// Reason reported in [`AStrError::Invalid`] for rejected values.
+    const REASON: &'static str;
+}
+
+macro_rules! validators {
+    ($($(#[$doc:meta])* $name:ident = $allowed:expr, $reason:literal;)*) => {$(
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name;
+
+        impl Validator for $name {
+            const ALLOWED: ByteSet = $allowed;
+            const REASON: &'static str = $reason;
+        }
+    )*};
+}
+
+validators! {
+    /// Any ASCII char, see [AsciiAStr](crate::AsciiAStr)
+    Ascii = ByteSet::range(0, 0x7f), "expected ASCII characters";
+    /// `0` to `9`
+    AsciiDigits = ByteSet::range(b'0', b'9'), "expected ASCII digits";
+    /// `A` to `Z`
+    AsciiUpper = ByteSet::range(b'A', b'Z'), "expected upper case ASCII letters";
+    /// `0` to `9`, `a` to `z` and `A` to `Z`
+    AsciiAlnum = ByteSet::range(b'0', b'9')
+        .union(ByteSet::range(b'a', b'z'))
+        .union(ByteSet::range(b'A', b'Z')), "expected ASCII letters or digits";
+    /// `0` to `9` and `a` to `f`
+    HexLower = ByteSet::range(b'0', b'9').union(ByteSet::range(b'a', b'f')),
+        "expected lower case hex digits";
+    /// The RFC 4648 base32 alphabet, `A` to `Z` and `2` to `7`, without padding
+    Base32 = ByteSet::range(b'A', b'Z').union(ByteSet::range(b'2', b'7')),
+        "expected base32 characters";
+    /// Printable ASCII, from `' '` to `'~'`
+    Printable = ByteSet::range(b' ', b'~'), "expected printable ASCII characters";
+}
+
+/// An [AStr] that only holds bytes allowed by the [Validator] `V`.
+///
+/// The content is checked by every constructor, including `FromStr`, `TryFrom`, [FixedStr]
+/// and deserialization. Use [`validated!`](crate::validated) to check literals at compile time.
+///
+/// ```rust
+/// use astr::{validate::AsciiDigits, validated, AStrError, Validated};
+///
+/// type Zip = Validated<5, AsciiDigits>;
+///
+/// const BERLIN: Zip = validated!(AsciiDigits, "10115");
+/// assert_eq!(BERLIN, "10115");
+/// assert_eq!(
+///     "1011x".parse::<Zip>(),
+///     Err(AStrError::Invalid { reason: "expected ASCII digits" })
+/// );
+/// ```
+#[repr(transparent)]
+pub struct Validated<const N: usize, V> {
+    value: AStr<N>,
+    validator: PhantomData<fn() -> V>,
+}
+
+impl<const N: usize, V: Validator> Validated<N, V> {
+    /// Check the value against `V`.
+    pub const fn new(value: AStr<N>) -> Result<Self, AStrError> {
+        match V::ALLOWED.find_invalid(value.as_bytes()) {
+            Some(_) => Err(AStrError::Invalid { reason: V::REASON }),
+            None => Ok(Self {
+                value,
+                validator: PhantomData,
+            }),
+        }
+    }
+
+    /// Create a new value from a str, for use in `const` items.
+    ///
+    /// # Panics
+    /// Panics if the str has the wrong length or is rejected by `V`.
+    #[track_caller]
+    pub const fn from_str_const(s: &str) -> Self {
+        match Self::new(AStr::from_str_const(s)) {
+            Ok(value) => value,
+            Err(_) => panic!("{}", V::REASON),
+        }
+    }
+
+    /// # Safety
+    /// The value must be accepted by `V`.
+    pub(crate) const unsafe fn new_unchecked(value: AStr<N>) -> Self {
+        Self {
+            value,
+            validator: PhantomData,
+        }
+    }
+
+    /// # Safety
+    /// The value must still be accepted by `V` when the borrow ends.
+    pub(crate) const unsafe fn as_astr_mut(&mut self) -> &mut AStr<N> {
+        &mut self.value
+    }
+
+    /// Get the validated [AStr].
+    pub const fn as_astr(&self) -> &AStr<N> {
+        &self.value
+    }
+
+    /// Get the validated [AStr].
+    pub const fn into_inner(self) -> AStr<N> {
+        self.value
+    }
+
+    /// Get the str.
+    pub const fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+/// Build a [Validated] from a literal, checked at compile time.
+///
+/// ```rust
+/// use astr::{validate::HexLower, validated};
+///
+/// let color = validated!(HexLower, "fa8072");
+/// assert_eq!(color, "fa8072");
+/// ```
+///
+/// Rejected literals fail to compile.
+///
+/// ```rust,compile_fail
+/// use astr::{validate::HexLower, validated};
+///
+/// let color = validated!(HexLower, "FA8072");
+/// ```
+#[macro_export]
+macro_rules! validated {
+    ($validator:ty, $input:expr) => {{
+        const LEN: usize = $input.len();
+        const RET: $crate::Validated<LEN, $validator> = $crate::Validated::from_str_const($input);
+        RET
+    }};
+}
+
+impl<const N: usize, V> Clone for Validated<N, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, V> Copy for Validated<N, V> {}
+
+impl<const N: usize, V> PartialEq for Validated<N, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<const N: usize, V> Eq for Validated<N, V> {}
+
+impl<const N: usize, V> PartialOrd for Validated<N, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, V> Ord for Validated<N, V> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        Ord::cmp(&self.value, &other.value)
+    }
+}
+
+impl<const N: usize, V> core::hash::Hash for Validated<N, V> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<const N: usize, V> PartialEq<str> for Validated<N, V> {
+    fn eq(&self, other: &str) -> bool {
+        self.value == *other
+    }
+}
+
+impl<const N: usize, V> PartialEq<&str> for Validated<N, V> {
+    fn eq(&self, other: &&str) -> bool {
+        self.value == *other
+    }
+}
+
+impl<const N: usize, V> PartialEq<AStr<N>> for Validated<N, V> {
+    fn eq(&self, other: &AStr<N>) -> bool {
+        self.value == *other
+    }
+}
+
+impl<const N: usize, V> core::ops::Deref for Validated<N, V> {
+    type Target = AStr<N>;
+
+    fn deref(&self) -> &AStr<N> {
+        &self.value
+    }
+}
+
+impl<const N: usize, V> AsRef<str> for Validated<N, V> {
+    fn as_ref(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl<const N: usize, V> AsRef<AStr<N>> for Validated<N, V> {
+    fn as_ref(&self) -> &AStr<N> {
+        &self.value
+    }
+}
+
+impl<const N: usize, V> core::borrow::Borrow<str> for Validated<N, V> {
+    fn borrow(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl<const N: usize, V> core::fmt::Debug for Validated<N, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl<const N: usize, V> core::fmt::Display for Validated<N, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<const N: usize, V: Validator> core::str::FromStr for Validated<N, V> {
+    type Err = AStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(*AStr::try_from_str_ref(s)?)
+    }
+}
+
+impl<const N: usize, V: Validator> TryFrom<&str> for Validated<N, V> {
+    type Error = AStrError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<const N: usize, V: Validator> TryFrom<AStr<N>> for Validated<N, V> {
+    type Error = AStrError;
+
+    fn try_from(value: AStr<N>) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<const N: usize, V> From<Validated<N, V>> for AStr<N> {
+    fn from(value: Validated<N, V>) -> Self {
+        value.value
+    }
+}
+
+impl<const N: usize, V> DynFixedStr for Validated<N, V> {
+    fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl<const N: usize, V: Validator> FixedStr for Validated<N, V> {
+    const LEN: usize = N;
+
+    fn try_from_str(s: &str) -> Result<Self, AStrError> {
+        s.parse()
+    }
+
+    fn try_from_utf8(bytes: &[u8]) -> Result<Self, AStrError> {
+        Self::new(*AStr::try_from_utf8(bytes)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{Validated, Validator};
+    use crate::AStr;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<const N: usize, V> Serialize for Validated<N, V> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.value.serialize(serializer)
+        }
+    }
+
+    impl<'de, const N: usize, V: Validator> Deserialize<'de> for Validated<N, V> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let value = AStr::<N>::deserialize(deserializer)?;
+            Self::new(value).map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AsciiAlnum, AsciiDigits, AsciiUpper, Base32, ByteSet, HexLower, Printable, Validated,
+        Validator,
+    };
+    use crate::{AStrError, FixedStr};
+
+    fn check<V: Validator>(s: &str) -> bool {
+        V::ALLOWED.find_invalid(s.as_bytes()).is_none()
+    }
+
+    #[test]
+    fn test_byte_set() {
+        const SET: ByteSet = ByteSet::bytes(b"az\xff").union(ByteSet::range(b'0', b'2'));
+        assert!([b'a', b'z', 0xff, b'0', b'1', b'2'].map(|b| SET.contains(b)) == [true; 6]);
+        assert!(!SET.contains(b'b') && !SET.contains(0) && !SET.contains(b'3'));
+        assert_eq!(SET.find_invalid(b"a0zb"), Some(3));
+        assert_eq!(ByteSet::range(1, 0), ByteSet::EMPTY);
+        assert_eq!(ByteSet::range(0, 255).find_invalid(&[0, 127, 255]), None);
+    }
+
+    #[test]
+    fn test_validators() {
+        assert!(check::<AsciiDigits>("0129") && !check::<AsciiDigits>("01a"));
+        assert!(check::<AsciiUpper>("AZ") && !check::<AsciiUpper>("Az"));
+        assert!(check::<AsciiAlnum>("aZ09") && !check::<AsciiAlnum>("a-b"));
+        assert!(check::<HexLower>("09af") && !check::<HexLower>("09aF"));
+        assert!(check::<Base32>("AZ27") && !check::<Base32>("AZ18"));
+        assert!(
+            check::<Printable>(" ~aZ") && !check::<Printable>("a\tb") && !check::<Printable>("ä")
+        );
+    }
+
+    #[test]
+    fn test_validated() {
+        const ZIP: Validated<5, AsciiDigits> = crate::validated!(AsciiDigits, "10115");
+        assert_eq!(ZIP, "10115");
+        assert_eq!(ZIP.len(), 5);
+
+        let invalid = Err(AStrError::Invalid {
+            reason: "expected ASCII digits",
+        });
+        assert_eq!("1011x".parse::<Validated<5, AsciiDigits>>(), invalid);
+        assert_eq!(
+            Validated::<5, AsciiDigits>::try_from_utf8(b"1011x"),
+            invalid
+        );
+        assert_eq!(
+            Validated::<5, AsciiDigits>::try_from(*crate::astr!("1011x")),
+            invalid
+        );
+        assert!(matches!(
+            Validated::<5, AsciiDigits>::try_from("101"),
+            Err(AStrError::Length { .. })
+        ));
+        assert_eq!(crate::AStr::from(ZIP), "10115");
+    }
+
+    #[test]
+    #[should_panic = "expected lower case hex digits"]
+    fn test_from_str_const_panic() {
+        let _ = Validated::<2, HexLower>::from_str_const("AB");
+    }
+}